@@ -355,9 +355,25 @@ fn make_constructor_and_default(
                     Gd::from_obj_sys(__object_ptr)
                 }
             }
+
+            /// Returns the singleton instance, or `None` if it is not (yet) registered with the engine.
+            ///
+            /// Unlike [`singleton()`][Self::singleton], this never panics, e.g. before the engine is fully initialized.
+            pub fn singleton_checked() -> Option<Gd<Self>> {
+                Self::is_singleton_available().then(Self::singleton)
+            }
+
+            /// Returns whether the singleton instance is currently registered with the engine.
+            ///
+            /// See also [`Engine::has_singleton()`][crate::classes::Engine::has_singleton].
+            pub fn is_singleton_available() -> bool {
+                let __class_name = #godot_class_stringname;
+                crate::classes::Engine::singleton().has_singleton(&__class_name)
+            }
         };
         construct_doc = "# Singleton\n\n\
-            This class is a singleton. You can get the one instance using [`Self::singleton()`][Self::singleton].";
+            This class is a singleton. You can get the one instance using [`Self::singleton()`][Self::singleton], \
+            or [`Self::singleton_checked()`][Self::singleton_checked] if it may not be available.";
         has_godot_default_impl = false;
     } else if !class.is_instantiable {
         // Abstract base classes or non-singleton classes without constructor.
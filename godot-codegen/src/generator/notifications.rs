@@ -87,7 +87,9 @@ pub fn make_notification_enum(
 
     let mut notification_enumerators_shout = Vec::new();
     let mut notification_enumerators_ord = Vec::new();
+    let mut notification_enumerators_godot = Vec::new();
     for (constant_ident, constant_value) in all_constants {
+        notification_enumerators_godot.push(format!("NOTIFICATION_{constant_ident}"));
         notification_enumerators_shout.push(constant_ident);
         notification_enumerators_ord.push(constant_value);
     }
@@ -138,6 +140,23 @@ pub fn make_notification_enum(
                 }
             }
         }
+
+        impl #enum_name {
+            /// The name of the notification constant, as specified in Godot (e.g. `"NOTIFICATION_READY"`).
+            ///
+            /// Useful for debug output in `on_notification()`. Returns `None` for the [`Unknown`][Self::Unknown] variant.
+            ///
+            /// Different classes may assign the same integer to different notifications, so the name can only be determined with the
+            /// notification type of the receiving class.
+            pub fn godot_name(&self) -> Option<&'static str> {
+                match self {
+                    #(
+                        Self::#notification_enumerators_shout => Some(#notification_enumerators_godot),
+                    )*
+                    Self::Unknown(_) => None,
+                }
+            }
+        }
     };
 
     (Some(code), enum_name)
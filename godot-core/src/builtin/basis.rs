@@ -82,7 +82,26 @@ impl Basis {
         Self { rows: [x, y, z] }
     }
 
-    /// Create a new basis from 3 column vectors.
+    /// Create a new basis from 3 column vectors, which are the basis vectors (the transformed X, Y and Z axes).
+    ///
+    /// This is the counterpart to [`to_cols()`](Self::to_cols). For the transposed interpretation, see [`from_rows()`](Self::from_rows).
+    ///
+    /// _Godot equivalent: `Basis(Vector3 x_axis, Vector3 y_axis, Vector3 z_axis)`_
+    ///
+    /// # Example
+    /// Basis whose -Z axis (camera forward) points along `forward`, like [`new_looking_at()`](Self::new_looking_at) computes it:
+    /// ```
+    /// # use godot::prelude::*;
+    /// let forward = Vector3::new(1.0, 0.0, -1.0).normalized();
+    /// let right = forward.cross(Vector3::UP).normalized();
+    /// let up = right.cross(forward);
+    ///
+    /// let basis = Basis::from_cols(right, up, -forward);
+    /// assert_eq!(basis.col_a(), right);
+    /// assert_eq!(basis.col_c(), -forward);
+    /// ```
+    #[doc(alias = "from_columns")]
+    #[doc(alias = "from_vectors")]
     pub const fn from_cols(a: Vector3, b: Vector3, c: Vector3) -> Self {
         Self::from_rows_array(&[a.x, b.x, c.x, a.y, b.y, c.y, a.z, b.z, c.z])
     }
@@ -105,9 +124,9 @@ impl Basis {
         }
     }
 
-    /// Create a diagonal matrix from the given values.
+    /// Create a diagonal matrix that scales by the components of `scale`.
     ///
-    /// _Godot equivalent: `Basis.from_scale(Vector3 scale)`
+    /// _Godot equivalent: `Basis.from_scale(Vector3 scale)`_
     pub const fn from_scale(scale: Vector3) -> Self {
         Self::from_diagonal(scale.x, scale.y, scale.z)
     }
@@ -703,6 +722,28 @@ mod test {
         assert_eq_approx!(Basis::FLIP_Z * v, Vector3::new(v.x, v.y, -v.z),);
     }
 
+    #[test]
+    fn constructors_agree() {
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let b = Vector3::new(4.0, 5.0, 6.0);
+        let c = Vector3::new(7.0, 8.0, 9.0);
+
+        let from_cols = Basis::from_cols(a, b, c);
+        assert_eq!(from_cols.to_cols(), [a, b, c]);
+        assert_eq!(from_cols, Basis::from_rows(a, b, c).transposed());
+
+        let scale = Vector3::new(2.0, -1.0, 0.5);
+        assert_eq!(
+            Basis::from_scale(scale),
+            Basis::from_cols(
+                Vector3::new(scale.x, 0.0, 0.0),
+                Vector3::new(0.0, scale.y, 0.0),
+                Vector3::new(0.0, 0.0, scale.z),
+            )
+        );
+        assert_eq!(Basis::from_scale(scale) * a, a * scale);
+    }
+
     #[test]
     fn basic_rotation_correct() {
         assert_eq_approx!(
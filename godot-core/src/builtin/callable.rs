@@ -9,7 +9,8 @@ use godot_ffi as sys;
 
 use crate::builtin::{inner, GString, StringName, Variant, VariantArray};
 use crate::classes;
-use crate::meta::{GodotType, ToGodot};
+use crate::meta::error::CallError;
+use crate::meta::{CallContext, GodotType, ToGodot};
 use crate::obj::bounds::DynMemory;
 use crate::obj::Bounds;
 use crate::obj::{Gd, GodotClass, InstanceId};
@@ -290,6 +291,40 @@ impl Callable {
         self.as_inner().callv(arguments)
     }
 
+    /// Calls the method represented by this callable, returning an error instead of failing silently.
+    ///
+    /// Unlike [`callv()`][Self::callv], this reports invalid callables, wrong argument counts and type mismatches as a [`CallError`].
+    /// Errors inside a Rust `#[func]` or custom callable are attached as [source][std::error::Error::source] of the returned error.
+    ///
+    /// Useful when the callable comes from an untrusted place, for example a GDScript plugin.
+    ///
+    /// _Godot equivalent: `call`_
+    pub fn try_call(&self, args: &[Variant]) -> Result<Variant, CallError> {
+        let self_variant = self.to_variant();
+        let method = StringName::from("call");
+        let args_sys: Vec<_> = args.iter().map(|v| v.var_sys()).collect();
+        let mut error = sys::default_call_error();
+
+        // SAFETY: variant_call() with a Callable variant and method "call" forwards to Callable::callp(); all pointers are valid.
+        let result = unsafe {
+            Variant::new_with_var_uninit(|variant_ptr| {
+                sys::interface_fn!(variant_call)(
+                    sys::SysPtr::force_mut(self_variant.var_sys()),
+                    method.string_sys(),
+                    args_sys.as_ptr(),
+                    args_sys.len() as i64,
+                    variant_ptr,
+                    ptr::addr_of_mut!(error),
+                )
+            })
+        };
+
+        let call_ctx = CallContext::outbound("Callable", "call");
+        CallError::check_out_varcall(&call_ctx, error, &[] as &[Variant], args)?;
+
+        Ok(result)
+    }
+
     /// Returns a copy of this Callable with one or more arguments bound, reading them from an array.
     ///
     /// _Godot equivalent: `bindv`_
@@ -367,6 +402,22 @@ impl Callable {
         self.as_inner().is_custom()
     }
 
+    /// Returns name and object of a custom callable, or `None` for standard (object + method) callables.
+    ///
+    /// Unlike [`method_name()`][Self::method_name], this does not cause a Godot error for custom callables. The name is the callable's
+    /// string representation: for [`from_local_fn()`][Self::from_local_fn] and related constructors, this is the name passed on creation;
+    /// for [`RustCallable`] implementations, it is their `Display` output.
+    pub fn custom_info(&self) -> Option<CallableInfo> {
+        if !self.is_custom() {
+            return None;
+        }
+
+        Some(CallableInfo {
+            name: self.to_string(),
+            object: self.object(),
+        })
+    }
+
     /// Returns true if this callable has no target to call the method on.
     ///
     /// This is not the negated form of [`is_valid`][Self::is_valid], as `is_valid` will return `false` if the callable has a
@@ -474,6 +525,16 @@ impl fmt::Display for Callable {
     }
 }
 
+/// Introspection data of a custom callable, returned by [`Callable::custom_info()`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct CallableInfo {
+    /// Human-readable name of the callable, as shown by Godot.
+    pub name: String,
+
+    /// Object associated with the callable, if any and still alive. `None` for callables created from Rust functions.
+    pub object: Option<Gd<classes::Object>>,
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Callbacks for custom implementations
 
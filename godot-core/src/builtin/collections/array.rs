@@ -81,6 +81,29 @@ use sys::{ffi_methods, interface_fn, GodotFfi};
 /// assert_eq!(array.front(), Some(50));
 /// ```
 ///
+/// # Typed object array example
+///
+/// Object arrays like GDScript's `Array[Node3D]` are represented as `Array<Gd<Node3D>>`. The element class is set on the Godot array
+/// when it is created, so Godot rejects objects of other classes, and Rust code gets `Gd<Node3D>` back without casting.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// let mut nodes: Array<Gd<Node3D>> = Array::new();
+/// nodes.push(&Node3D::new_alloc());
+/// nodes.push(&Node3D::new_alloc());
+///
+/// let first: Option<Gd<Node3D>> = nodes.get(0);
+/// for mut node in nodes.iter_shared() {
+///     node.set_visible(false);
+/// }
+///
+/// while let Some(node) = nodes.pop() {
+///     node.free();
+/// }
+/// ```
+///
+/// If the array may contain null elements, use `Array<Option<Gd<T>>>` instead.
+///
 /// # Untyped array example
 ///
 /// ```no_run
@@ -554,6 +577,58 @@ impl<T: ArrayElement> Array<T> {
         }
     }
 
+    /// Returns an iterator over all contiguous windows of length `size`, which overlap.
+    ///
+    /// Like [`slice::windows()`], but each window is a `Vec` converted lazily from the array. If the array is shorter than `size`,
+    /// the iterator yields nothing. See [`iter_shared()`][Self::iter_shared] for behavior under concurrent modification.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let array = array![1, 2, 3, 4];
+    /// let sums: Vec<i64> = array.windows(2).map(|w| w.iter().sum()).collect();
+    /// assert_eq!(sums, [3, 5, 7]);
+    /// ```
+    ///
+    /// # Panics
+    /// If `size` is 0.
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = Vec<T>> + '_ {
+        assert!(size != 0, "window size must be non-zero");
+
+        (0..).map_while(move |start| {
+            // None if the array is too short for this window.
+            (start..start + size)
+                .map(|index| self.get(index))
+                .collect::<Option<Vec<T>>>()
+        })
+    }
+
+    /// Returns an iterator over non-overlapping chunks of length `size`, starting at the beginning of the array.
+    ///
+    /// Like [`slice::chunks()`]: the last chunk is shorter if the length is not divisible by `size`. Each chunk is a `Vec` converted lazily
+    /// from the array. See [`iter_shared()`][Self::iter_shared] for behavior under concurrent modification.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let array = array![1, 2, 3, 4, 5];
+    /// let chunks: Vec<Vec<i64>> = array.chunks(2).collect();
+    /// assert_eq!(chunks, [vec![1, 2], vec![3, 4], vec![5]]);
+    /// ```
+    ///
+    /// # Panics
+    /// If `size` is 0.
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = Vec<T>> + '_ {
+        assert!(size != 0, "chunk size must be non-zero");
+
+        (0..).step_by(size).map_while(move |start| {
+            let end = self.len().min(start + size);
+            let chunk: Vec<T> = (start..end).map_while(|index| self.get(index)).collect();
+
+            (!chunk.is_empty()).then_some(chunk)
+        })
+    }
+
     /// Returns the minimum value contained in the array if all elements are of comparable types.
     ///
     /// If the elements can't be compared or the array is empty, `None` is returned.
@@ -682,6 +757,77 @@ impl<T: ArrayElement> Array<T> {
         unsafe { self.as_inner_mut() }.shuffle();
     }
 
+    /// Returns a new array with consecutive duplicate elements removed, like [`Vec::dedup()`].
+    ///
+    /// Elements are compared with Godot's `==` semantics (see [`Variant`]'s `PartialEq`). To remove all duplicates, sort the array first.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let array = array![1, 1, 2, 3, 3, 1];
+    /// assert_eq!(array.dedup(), array![1, 2, 3, 1]);
+    /// ```
+    #[must_use]
+    pub fn dedup(&self) -> Self {
+        let mut result = self.duplicate_shallow();
+        result.dedup_in_place();
+        result
+    }
+
+    /// Removes consecutive duplicate elements from this array, like [`Vec::dedup()`].
+    ///
+    /// See [`dedup()`][Self::dedup] for details on comparison.
+    pub fn dedup_in_place(&mut self) {
+        self.dedup_by_variant_key(Variant::clone);
+    }
+
+    /// Returns a new array in which consecutive elements mapping to the same key are collapsed into the first one, like
+    /// [`Vec::dedup_by_key()`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let words: Array<GString> = array!["apple", "avocado", "banana", "cherry", "cranberry"];
+    /// let firsts = words.dedup_by_key(|word| word.to_string().chars().next());
+    /// assert_eq!(firsts, array!["apple", "banana", "cherry"]);
+    /// ```
+    #[must_use]
+    pub fn dedup_by_key<K: PartialEq>(&self, mut key: impl FnMut(&T) -> K) -> Self {
+        let mut result = self.duplicate_shallow();
+        result.dedup_by_variant_key(|variant| key(&T::from_variant(variant)));
+        result
+    }
+
+    /// Moves each element whose key differs from its predecessor's to the front, then truncates the rest.
+    fn dedup_by_variant_key<K: PartialEq>(&mut self, mut key: impl FnMut(&Variant) -> K) {
+        let len = self.len();
+        if len < 2 {
+            return;
+        }
+
+        let kept = {
+            // SAFETY: `self` has `len` elements, which are all valid `Variant`s. Unless `experimental-threads` is enabled, there is no
+            // concurrent access to the array, and we do not access it otherwise while this slice exists.
+            let elements = unsafe { Variant::borrow_slice_mut(self.ptr_mut(0), len) };
+
+            let mut last_key = key(&elements[0]);
+            let mut kept = 1;
+            for index in 1..len {
+                let current_key = key(&elements[index]);
+                if current_key != last_key {
+                    // Only reorders existing elements, so all values keep type `T`.
+                    elements.swap(index, kept);
+                    kept += 1;
+                    last_key = current_key;
+                }
+            }
+
+            kept
+        };
+
+        self.shrink(kept);
+    }
+
     /// Asserts that the given index refers to an existing element.
     ///
     /// # Panics
@@ -920,6 +1066,53 @@ fn correct_variant_t() {
 }
 
 impl VariantArray {
+    /// Returns an iterator that converts each element to `T`.
+    ///
+    /// Elements that cannot be converted are yielded as `Err`. The error holds the failing element as its [`value()`][ConvertError::value],
+    /// and the element index plus the original conversion error as its [`source()`][std::error::Error::source]. Iteration continues after
+    /// such errors, so you can decide whether to skip them or abort (e.g. via `collect::<Result<_, _>>()`).
+    ///
+    /// See [`iter_shared()`][Self::iter_shared] for behavior under concurrent modification.
+    pub fn iter_typed<T: FromGodot>(&self) -> impl Iterator<Item = Result<T, ConvertError>> + '_ {
+        self.iter_shared().enumerate().map(|(index, element)| {
+            element
+                .try_to::<T>()
+                .map_err(|err| element_convert_error(index, err))
+        })
+    }
+
+    /// Converts all elements to `T` and returns an iterator over them, or the first conversion error.
+    ///
+    /// Useful for arrays that are expected to be homogeneous. Unlike [`iter_typed()`][Self::iter_typed], all elements are converted up-front.
+    pub fn iter_all_typed<T: FromGodot>(&self) -> Result<impl Iterator<Item = T>, ConvertError> {
+        let elements = self.iter_typed().collect::<Result<Vec<T>, _>>()?;
+        Ok(elements.into_iter())
+    }
+
+    /// Like [`windows()`][Self::windows], but converts the elements of each window to `T`.
+    ///
+    /// A window with an element that cannot be converted is yielded as `Err`, with the same error structure as
+    /// [`iter_typed()`][Self::iter_typed]. Iteration continues after such errors.
+    ///
+    /// # Panics
+    /// If `size` is 0.
+    pub fn windows_typed<T: FromGodot>(
+        &self,
+        size: usize,
+    ) -> impl Iterator<Item = Result<Vec<T>, ConvertError>> + '_ {
+        self.windows(size).enumerate().map(|(start, window)| {
+            window
+                .into_iter()
+                .enumerate()
+                .map(|(offset, element)| {
+                    element
+                        .try_to::<T>()
+                        .map_err(|err| element_convert_error(start + offset, err))
+                })
+                .collect()
+        })
+    }
+
     /// # Safety
     /// - Variant must have type `VariantType::ARRAY`.
     /// - Subsequent operations on this array must not rely on the type of the array.
@@ -932,6 +1125,44 @@ impl VariantArray {
     }
 }
 
+/// Wraps the conversion error of the element at `index`, keeping the failing value and the original error as source.
+fn element_convert_error(index: usize, err: ConvertError) -> ConvertError {
+    let value = err.value().cloned();
+    let cause = ElementConvertError {
+        index,
+        cause: Box::new(err.into_erased()),
+    };
+
+    match value {
+        Some(value) => ConvertError::with_error_value(cause, value),
+        None => ConvertError::with_error(cause),
+    }
+}
+
+struct ElementConvertError {
+    index: usize,
+    cause: Box<dyn std::error::Error + Send + Sync>,
+}
+
+impl fmt::Display for ElementConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "element at index {}: {}", self.index, self.cause)
+    }
+}
+
+// ConvertError formats custom causes with `Debug`, so keep this readable.
+impl fmt::Debug for ElementConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for ElementConvertError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.cause)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Traits
 
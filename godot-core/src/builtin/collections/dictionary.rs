@@ -8,6 +8,7 @@
 use godot_ffi as sys;
 
 use crate::builtin::{inner, Variant, VariantArray};
+use crate::meta::error::{ConvertError, DictError};
 use crate::meta::{FromGodot, ToGodot};
 use sys::types::OpaqueDictionary;
 use sys::{ffi_methods, interface_fn, GodotFfi};
@@ -40,6 +41,9 @@ use std::{fmt, ptr};
 ///    coord: "Tile77",
 /// };
 ///
+/// // Collect key-value pairs of the same types, with FromIterator.
+/// let scores = Dictionary::from_iter([("key", 42), ("other", 100)]);
+///
 /// // Access elements.
 /// let value: Variant = dict.at("str");
 /// let value: GString = dict.at("str").to(); // Variant::to() extracts GString.
@@ -125,6 +129,43 @@ impl Dictionary {
         }
     }
 
+    /// Returns the value for the given key, converted to `V`.
+    ///
+    /// Returns [`DictError::KeyNotFound`] if the key is absent, or [`DictError::TypeMismatch`] if the value cannot be converted to `V`.
+    /// A `NIL` value is only accepted if `V` can represent it, e.g. `Option<Gd<T>>`.
+    ///
+    /// This is a shorthand for `dict.get(key)` followed by [`Variant::try_to()`].
+    pub fn try_get_typed<K: ToGodot, V: FromGodot>(&self, key: K) -> Result<V, DictError> {
+        self.get_typed_impl(key.to_variant())
+    }
+
+    /// ⚠️ Returns the value for the given key, converted to `V`, or panics.
+    ///
+    /// See [`try_get_typed()`][Self::try_get_typed] for the fallible version.
+    ///
+    /// # Panics
+    ///
+    /// If there is no value for the given key, or if the value cannot be converted to `V`.
+    pub fn at_typed<K: ToGodot, V: FromGodot>(&self, key: K) -> V {
+        self.get_typed_impl(key.to_variant())
+            .unwrap_or_else(|err| panic!("at_typed(): {err}"))
+    }
+
+    fn get_typed_impl<V: FromGodot>(&self, key: Variant) -> Result<V, DictError> {
+        let Some(value) = self.get(key.clone()) else {
+            return Err(DictError::KeyNotFound { key });
+        };
+
+        value
+            .try_to::<V>()
+            .map_err(|source| DictError::TypeMismatch {
+                key,
+                expected: std::any::type_name::<V>(),
+                actual: value.get_type(),
+                source,
+            })
+    }
+
     /// Returns the value at the key in the dictionary, or `NIL` otherwise.
     ///
     /// This method does not let you differentiate `NIL` values stored as values from absent keys.
@@ -294,7 +335,9 @@ impl Dictionary {
     /// Note that it's possible to modify the `Dictionary` through another reference while iterating over it. This will not result in
     /// unsoundness or crashes, but will cause the iterator to behave in an unspecified way.
     ///
-    /// Use `dict.iter_shared().typed::<K, V>()` to iterate over `(K, V)` pairs instead.
+    /// Use `dict.iter_shared().typed::<K, V>()` to iterate over `(K, V)` pairs instead, or `dict.iter_shared().try_typed::<K, V>()`
+    /// if conversions may fail.
+    #[doc(alias = "iter")]
     pub fn iter_shared(&self) -> Iter<'_> {
         Iter::new(self)
     }
@@ -570,6 +613,15 @@ impl<'a> Iter<'a> {
     pub fn typed<K: FromGodot, V: FromGodot>(self) -> TypedIter<'a, K, V> {
         TypedIter::from_untyped(self)
     }
+
+    /// Creates an iterator that converts each `(Variant, Variant)` key-value pair into a `(K, V)` key-value
+    /// pair, yielding an error for pairs that cannot be converted.
+    ///
+    /// Iteration continues after a failed conversion, so use [`Iterator::flatten()`] to skip those pairs, or collect into a
+    /// `Result<Vec<_>, _>` to stop at the first error.
+    pub fn try_typed<K: FromGodot, V: FromGodot>(self) -> TryTypedIter<'a, K, V> {
+        TryTypedIter::from_untyped(self)
+    }
 }
 
 impl Iterator for Iter<'_> {
@@ -663,6 +715,43 @@ impl<K: FromGodot, V: FromGodot> Iterator for TypedIter<'_, K, V> {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// [`Dictionary`] iterator that tries to convert each key-value pair into a typed `(K, V)`.
+///
+/// See [`Dictionary::iter_shared()`] for more information about iteration over dictionaries.
+pub struct TryTypedIter<'a, K, V> {
+    iter: DictionaryIter<'a>,
+    _k: PhantomData<K>,
+    _v: PhantomData<V>,
+}
+
+impl<'a, K, V> TryTypedIter<'a, K, V> {
+    fn from_untyped(value: Iter<'a>) -> Self {
+        Self {
+            iter: value.iter,
+            _k: PhantomData,
+            _v: PhantomData,
+        }
+    }
+}
+
+impl<K: FromGodot, V: FromGodot> Iterator for TryTypedIter<'_, K, V> {
+    type Item = Result<(K, V), ConvertError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next_key_value().map(|(key, value)| {
+            let key = K::try_from_variant(&key)?;
+            let value = V::try_from_variant(&value)?;
+            Ok((key, value))
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// [`Dictionary`] iterator that converts each key into a typed `K`.
 ///
 /// See [`Dictionary::iter_shared()`] for more information about iteration over dictionaries.
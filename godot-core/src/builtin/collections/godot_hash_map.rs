@@ -0,0 +1,243 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::builtin::Dictionary;
+use crate::meta::error::ConvertError;
+use crate::meta::{FromGodot, GodotConvert, ToGodot};
+use crate::registry::property::{Export, PropertyHintInfo, Var};
+
+/// Map with statically typed keys and values, stored in a Godot [`Dictionary`].
+///
+/// `GodotHashMap<K, V>` offers an interface similar to [`HashMap`][std::collections::HashMap], while keeping the data in a Godot-compatible
+/// representation. This is useful for state that must be stored in Godot types, e.g. fields of a `Resource` subclass that is saved to disk.
+///
+/// # Conversions
+/// Keys and values are converted to/from [`Variant`][crate::builtin::Variant] on every access. When converting from a `Dictionary` (e.g. through
+/// [`try_from_dictionary()`][Self::try_from_dictionary] or [`FromGodot`]), all entries are validated once.
+///
+/// # Reference semantics
+/// Like `Dictionary`, the underlying storage is reference-counted. [`Clone`] creates a new reference to the same data; use
+/// [`duplicate()`][Self::duplicate] for an independent copy. Mutating methods require `&mut self`, but other references to the same
+/// `Dictionary` (including ones obtained through [`as_dictionary()`][Self::as_dictionary]) can still observe and cause changes.
+///
+/// If the dictionary is modified elsewhere to contain keys or values of other types, access panics when encountering such an entry.
+pub struct GodotHashMap<K, V> {
+    dict: Dictionary,
+    _phantom: PhantomData<fn() -> (K, V)>,
+}
+
+impl<K, V> GodotHashMap<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        Self::from_dictionary_unchecked(Dictionary::new())
+    }
+
+    /// Wraps an existing dictionary, validating that all keys are convertible to `K` and all values to `V`.
+    ///
+    /// The dictionary is not copied; the map refers to the same data.
+    pub fn try_from_dictionary(dict: Dictionary) -> Result<Self, ConvertError> {
+        for (key, value) in dict.iter_shared() {
+            key.try_to::<K>()?;
+            value.try_to::<V>()?;
+        }
+
+        Ok(Self::from_dictionary_unchecked(dict))
+    }
+
+    fn from_dictionary_unchecked(dict: Dictionary) -> Self {
+        Self {
+            dict,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Inserts a key-value pair, returning the previous value for `key`, if any.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.dict.insert(key, value).map(|old| old.to::<V>())
+    }
+
+    /// Returns the value for `key`, or `None` if absent.
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.dict.get(key.to_variant()).map(|value| value.to::<V>())
+    }
+
+    /// Removes `key` from the map, returning its value, if any.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.dict.remove(key.to_variant()).map(|old| old.to::<V>())
+    }
+
+    /// Returns `true` if the map contains `key`.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.dict.contains_key(key.to_variant())
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.dict.len()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.dict.is_empty()
+    }
+
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.dict.clear()
+    }
+
+    /// Returns an iterator over all key-value pairs, in insertion order.
+    ///
+    /// Keys and values are returned by value, since they are converted from `Variant`. See [`Dictionary::iter_shared()`] for behavior
+    /// under concurrent modification.
+    pub fn iter(&self) -> impl Iterator<Item = (K, V)> + '_ {
+        self.dict.iter_shared().typed::<K, V>()
+    }
+
+    /// Returns an iterator over all keys, in insertion order.
+    pub fn keys(&self) -> impl Iterator<Item = K> + '_ {
+        self.dict.keys_shared().typed::<K>()
+    }
+
+    /// Returns an independent copy of this map. Keys and values are copied shallowly, like [`Dictionary::duplicate_shallow()`].
+    pub fn duplicate(&self) -> Self {
+        Self::from_dictionary_unchecked(self.dict.duplicate_shallow())
+    }
+
+    /// Returns the underlying dictionary.
+    pub fn as_dictionary(&self) -> &Dictionary {
+        &self.dict
+    }
+
+    /// Converts into the underlying dictionary.
+    pub fn into_dictionary(self) -> Dictionary {
+        self.dict
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Traits
+
+impl<K, V> Default for GodotHashMap<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Creates a new reference to the same data. See [`duplicate()`][Self::duplicate] for an independent copy.
+impl<K, V> Clone for GodotHashMap<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            dict: self.dict.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<K, V> PartialEq for GodotHashMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dict == other.dict
+    }
+}
+
+impl<K, V> fmt::Debug for GodotHashMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.dict, f)
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for GodotHashMap<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V> Extend<(K, V)> for GodotHashMap<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.dict.set(key, value);
+        }
+    }
+}
+
+impl<K, V> GodotConvert for GodotHashMap<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    type Via = Dictionary;
+}
+
+impl<K, V> ToGodot for GodotHashMap<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    type ToVia<'v>
+        = Dictionary
+    where
+        Self: 'v;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        self.dict.clone()
+    }
+}
+
+impl<K, V> FromGodot for GodotHashMap<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Self::try_from_dictionary(via)
+    }
+}
+
+impl<K, V> Var for GodotHashMap<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    fn get_property(&self) -> Self::Via {
+        self.to_godot()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        *self = FromGodot::from_godot(value);
+    }
+}
+
+impl<K, V> Export for GodotHashMap<K, V>
+where
+    K: ToGodot + FromGodot,
+    V: ToGodot + FromGodot,
+{
+    fn export_hint() -> PropertyHintInfo {
+        <Dictionary as Export>::export_hint()
+    }
+}
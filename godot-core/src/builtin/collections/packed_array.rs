@@ -781,6 +781,286 @@ impl_packed_array!(
     },
 );
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Geometry-specific functions
+
+impl PackedVector2Array {
+    /// Returns a new array of the same length, with `f` applied to each element.
+    ///
+    /// The result is allocated once up-front, so this is cheaper than pushing elements one by one.
+    pub fn map_to_packed(&self, f: impl Fn(Vector2) -> Vector2) -> PackedVector2Array {
+        let src = self.as_slice();
+        let mut result = PackedVector2Array::default_with_size(src.len());
+
+        for (dst, &value) in result.as_mut_slice().iter_mut().zip(src) {
+            *dst = f(value);
+        }
+
+        result
+    }
+
+    /// Returns a new array with every point transformed by `transform`.
+    ///
+    /// Equivalent to `transform * point` for each element.
+    pub fn transform(&self, transform: &Transform2D) -> PackedVector2Array {
+        self.map_to_packed(|point| *transform * point)
+    }
+}
+
+impl PackedVector3Array {
+    /// Returns a new array of the same length, with `f` applied to each element.
+    ///
+    /// The result is allocated once up-front, so this is cheaper than pushing elements one by one.
+    pub fn map_to_packed(&self, f: impl Fn(Vector3) -> Vector3) -> PackedVector3Array {
+        let src = self.as_slice();
+        let mut result = PackedVector3Array::default_with_size(src.len());
+
+        for (dst, &value) in result.as_mut_slice().iter_mut().zip(src) {
+            *dst = f(value);
+        }
+
+        result
+    }
+
+    /// Returns a new array with every point transformed by `transform`.
+    ///
+    /// Equivalent to `transform * point` for each element.
+    pub fn transform(&self, transform: &Transform3D) -> PackedVector3Array {
+        self.map_to_packed(|point| *transform * point)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// String-specific functions
+
+impl PackedStringArray {
+    /// Concatenates all elements into a single string, with `separator` between each pair of elements.
+    ///
+    /// _Godot equivalent: `separator.join(array)`_
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let words: PackedStringArray = ["a", "b", "c"].into_iter().map(GString::from).collect();
+    /// assert_eq!(words.join(", "), GString::from("a, b, c"));
+    /// ```
+    pub fn join(&self, separator: impl AsArg<GString>) -> GString {
+        meta::arg_into_ref!(separator: GString);
+        separator.join(self)
+    }
+
+    /// Returns a new array of the same length, with `f` applied to each element.
+    ///
+    /// The result is allocated once up-front, so this is cheaper than pushing elements one by one.
+    pub fn map(&self, f: impl Fn(&GString) -> GString) -> PackedStringArray {
+        let src = self.as_slice();
+        let mut result = PackedStringArray::default_with_size(src.len());
+
+        for (dst, value) in result.as_mut_slice().iter_mut().zip(src) {
+            *dst = f(value);
+        }
+
+        result
+    }
+
+    /// Returns a new array with only the elements for which `predicate` returns `true`, in the same order.
+    pub fn filter(&self, predicate: impl Fn(&GString) -> bool) -> PackedStringArray {
+        self.as_slice()
+            .iter()
+            .filter(|value| predicate(value))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns `true` if `predicate` returns `true` for at least one element. Returns `false` for an empty array.
+    pub fn any(&self, predicate: impl Fn(&GString) -> bool) -> bool {
+        self.as_slice().iter().any(predicate)
+    }
+
+    /// Returns `true` if `predicate` returns `true` for all elements. Returns `true` for an empty array.
+    pub fn all(&self, predicate: impl Fn(&GString) -> bool) -> bool {
+        self.as_slice().iter().all(predicate)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Numeric statistics
+
+/// Summary statistics of a numeric packed array, computed in a single pass.
+///
+/// Returned by [`PackedFloat32Array::statistics()`] and [`PackedInt32Array::statistics()`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ArrayStats<T> {
+    /// Smallest element.
+    pub min: T,
+
+    /// Largest element.
+    pub max: T,
+
+    /// Arithmetic mean of all elements.
+    pub mean: f64,
+
+    /// Population variance of all elements (mean of squared deviations from `mean`).
+    pub variance: f64,
+
+    /// Number of elements.
+    pub len: usize,
+}
+
+impl<T> ArrayStats<T>
+where
+    T: Copy + PartialOrd + Into<f64>,
+{
+    /// Computes min, max, mean and variance using Welford's online algorithm, which is numerically stable.
+    fn from_slice(values: &[T]) -> Option<Self> {
+        let (&first, rest) = values.split_first()?;
+
+        let mut stats = Self {
+            min: first,
+            max: first,
+            mean: first.into(),
+            variance: 0.0,
+            len: 1,
+        };
+
+        // Holds the sum of squared deviations during iteration; divided by `len` at the end.
+        let mut m2 = 0.0;
+        for &value in rest {
+            if value < stats.min {
+                stats.min = value;
+            }
+            if value > stats.max {
+                stats.max = value;
+            }
+
+            stats.len += 1;
+            let x: f64 = value.into();
+            let delta = x - stats.mean;
+            stats.mean += delta / stats.len as f64;
+            m2 += delta * (x - stats.mean);
+        }
+
+        stats.variance = m2 / stats.len as f64;
+        Some(stats)
+    }
+}
+
+impl PackedFloat32Array {
+    /// Computes minimum, maximum, mean and variance of all elements in a single pass.
+    ///
+    /// Returns `None` if the array is empty. Mean and variance are accumulated in `f64` precision.
+    /// If the array contains NaN, `mean` and `variance` are NaN and `min`/`max` are unspecified.
+    pub fn statistics(&self) -> Option<ArrayStats<f32>> {
+        ArrayStats::from_slice(self.as_slice())
+    }
+}
+
+impl PackedInt32Array {
+    /// Computes minimum, maximum, mean and variance of all elements in a single pass.
+    ///
+    /// Returns `None` if the array is empty. Mean and variance are computed in `f64`, so they don't overflow.
+    pub fn statistics(&self) -> Option<ArrayStats<i32>> {
+        ArrayStats::from_slice(self.as_slice())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Procedural construction
+
+impl PackedInt32Array {
+    /// Creates an array with all integers in `range`, in ascending order.
+    ///
+    /// An unbounded start is treated as `0`, so `..5` yields `[0, 1, 2, 3, 4]`. Empty ranges yield an empty array.
+    ///
+    /// # Panics
+    /// If the end of the range is unbounded.
+    pub fn from_range(range: impl ops::RangeBounds<i32>) -> Self {
+        let start = match range.start_bound() {
+            ops::Bound::Included(&start) => i64::from(start),
+            ops::Bound::Excluded(&start) => i64::from(start) + 1,
+            ops::Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            ops::Bound::Included(&end) => i64::from(end) + 1,
+            ops::Bound::Excluded(&end) => i64::from(end),
+            ops::Bound::Unbounded => panic!("PackedInt32Array::from_range(): end must be bounded"),
+        };
+
+        let len = (end - start).max(0) as usize;
+        let mut result = Self::default_with_size(len);
+
+        for (dst, value) in result.as_mut_slice().iter_mut().zip(start..end) {
+            *dst = value as i32;
+        }
+
+        result
+    }
+}
+
+impl PackedFloat32Array {
+    /// Creates an array of `count` evenly spaced values from `start` to `end`, both inclusive.
+    ///
+    /// If `count` is 1, the array contains only `start`. If `count` is 0, it is empty.
+    pub fn from_linspace(start: f32, end: f32, count: usize) -> Self {
+        if count == 1 {
+            return Self::from(&[start]);
+        }
+
+        let step = (end - start) / (count as f32 - 1.0);
+        let mut result = Self::from_fn(count, |i| start + step * i as f32);
+
+        // Avoid rounding errors in the last element.
+        if let Some(last) = result.as_mut_slice().last_mut() {
+            *last = end;
+        }
+
+        result
+    }
+
+    /// Creates an array of `count` elements, where the element at index `i` is `f(i)`.
+    ///
+    /// The result is allocated once up-front, so this is cheaper than pushing elements one by one.
+    pub fn from_fn(count: usize, f: impl Fn(usize) -> f32) -> Self {
+        let mut result = Self::default_with_size(count);
+
+        for (i, dst) in result.as_mut_slice().iter_mut().enumerate() {
+            *dst = f(i);
+        }
+
+        result
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Byte-specific functions
+
+#[cfg(feature = "codegen-full")]
+impl PackedByteArray {
+    /// Encodes the bytes as a base64 string.
+    ///
+    /// _Godot equivalent: `Marshalls.raw_to_base64()`_
+    pub fn to_base64(&self) -> GString {
+        crate::classes::Marshalls::singleton().raw_to_base64(self)
+    }
+
+    /// Decodes a base64 string into bytes.
+    ///
+    /// Returns `None` if `base64` is not valid base64. In that case, Godot additionally prints an error.
+    ///
+    /// _Godot equivalent: `Marshalls.base64_to_raw()`_
+    pub fn from_base64(base64: impl AsArg<GString>) -> Option<Self> {
+        meta::arg_into_ref!(base64);
+
+        if base64.is_empty() {
+            return Some(Self::new());
+        }
+
+        // Godot returns an empty array on failure; non-empty valid input never decodes to zero bytes.
+        let bytes = crate::classes::Marshalls::singleton().base64_to_raw(base64);
+        (!bytes.is_empty()).then_some(bytes)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Abstractions that may later simplify the migration to generics.
 
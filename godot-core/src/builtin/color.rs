@@ -76,6 +76,16 @@ impl Color {
         Color::from_rgba8(r, g, b, a)
     }
 
+    /// Constructs a new `Color` from a 32-bits value in `0xRRGGBBAA` format.
+    ///
+    /// Shorthand for [`Color::from_u32_rgba(hex, ColorChannelOrder::RGBA)`][Self::from_u32_rgba].
+    ///
+    /// _Godot equivalent: `Color.hex`_
+    #[doc(alias = "hex")]
+    pub fn from_hex(hex: u32) -> Self {
+        Self::from_u32_rgba(hex, ColorChannelOrder::RGBA)
+    }
+
     /// Constructs a new `Color` from a 64-bits value with the given channel `order`.
     ///
     /// _Godot equivalent: `Color.hex64`, if `ColorChannelOrder::Rgba` is used_
@@ -186,6 +196,19 @@ impl Color {
         to_u8(self.a)
     }
 
+    /// Returns the red, green, blue and alpha channel values as bytes. Components outside the range from 0 to 1 are clamped.
+    ///
+    /// Inverse of [`Color::from_rgba8`], up to rounding.
+    pub fn to_rgba8(self) -> [u8; 4] {
+        [self.r8(), self.g8(), self.b8(), self.a8()]
+    }
+
+    /// Returns the red, green and blue channel values as bytes, ignoring alpha. Components outside the range from 0 to 1
+    /// are clamped.
+    pub fn to_rgb8(self) -> [u8; 3] {
+        [self.r8(), self.g8(), self.b8()]
+    }
+
     /// Sets the red channel value as a byte, mapped to the range from 0 to 1.
     pub fn set_r8(&mut self, r: u8) {
         self.r = from_u8(r);
@@ -287,6 +310,10 @@ impl Color {
 
     /// Returns the color converted to a 32-bit integer (each component is 8 bits) with the given
     /// `order` of channels (from most to least significant byte).
+    ///
+    /// For example, Godot's `to_abgr32()` is `to_u32(ColorChannelOrder::ABGR)`.
+    #[doc(alias = "to_abgr32")]
+    #[doc(alias = "to_abgr8")]
     pub fn to_u32(self, order: ColorChannelOrder) -> u32 {
         u32::from_be_bytes(order.pack([to_u8(self.r), to_u8(self.g), to_u8(self.b), to_u8(self.a)]))
     }
@@ -571,6 +598,41 @@ impl std::fmt::Display for Color {
 
 #[cfg(test)]
 mod test {
+    use super::*;
+
+    #[test]
+    fn rgba8_roundtrip() {
+        let color = Color::from_rgba8(0x00, 0x7f, 0xff, 0x80);
+        assert_eq!(color.to_rgba8(), [0x00, 0x7f, 0xff, 0x80]);
+        assert_eq!(color.to_rgb8(), [0x00, 0x7f, 0xff]);
+
+        assert_eq!(
+            Color::from_rgba8(0, 0, 0, 0),
+            Color::from_rgba(0.0, 0.0, 0.0, 0.0)
+        );
+        assert_eq!(Color::from_rgba8(255, 255, 255, 255), Color::WHITE);
+    }
+
+    #[test]
+    fn rgba8_clamped() {
+        let color = Color::from_rgba(-0.5, 1.5, 0.0, 1.0);
+        assert_eq!(color.to_rgba8(), [0x00, 0xff, 0x00, 0xff]);
+    }
+
+    #[test]
+    fn hex_conversions() {
+        let color = Color::from_hex(0x00_7f_ff_80);
+        assert_eq!(color.to_rgba8(), [0x00, 0x7f, 0xff, 0x80]);
+        assert_eq!(color.to_u32(ColorChannelOrder::ABGR), 0x80_ff_7f_00);
+
+        assert_eq!(
+            Color::from_hex(0x00000000).to_u32(ColorChannelOrder::ABGR),
+            0x00000000
+        );
+        assert_eq!(Color::from_hex(0xffffffff), Color::WHITE);
+        assert_eq!(Color::WHITE.to_u32(ColorChannelOrder::ABGR), 0xffffffff);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
@@ -34,6 +34,8 @@
 //!   overloading would become impossible](https://github.com/kvark/mint/issues/75).
 
 // Re-export macros.
+#[cfg(since_api = "4.2")]
+pub use crate::godot_select;
 pub use crate::{array, dict, real, reals, varray};
 
 // Re-export generated enums.
@@ -67,6 +69,8 @@ pub mod __prelude_reexport {
     pub use vectors::*;
 
     pub use super::{EulerOrder, Side, VariantOperator, VariantType};
+    #[cfg(since_api = "4.2")]
+    pub use crate::godot_select;
     pub use crate::{array, dict, real, reals, varray};
 }
 
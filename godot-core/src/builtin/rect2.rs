@@ -107,6 +107,7 @@ impl Rect2 {
     ///
     /// Note: This method is not reliable for `Rect2` with a negative size. Use [`abs`][Self::abs]
     /// to get a positive sized equivalent rectangle for expanding.
+    #[doc(alias = "expand_to_include")]
     #[inline]
     pub fn expand(self, to: Vector2) -> Self {
         self.merge(Rect2::new(to, Vector2::ZERO))
@@ -124,6 +125,35 @@ impl Rect2 {
         Self::from_corners(position, end)
     }
 
+    /// Returns the four corners of the rectangle: top-left, top-right, bottom-right, bottom-left.
+    ///
+    /// "Top" refers to the smaller Y coordinate, as in Godot's 2D coordinate system. For rectangles with negative size, the corners are
+    /// those of [`abs()`][Self::abs].
+    #[inline]
+    pub fn points(self) -> [Vector2; 4] {
+        let rect = self.abs();
+        let start = rect.position;
+        let end = rect.end();
+
+        [
+            start,
+            Vector2::new(end.x, start.y),
+            end,
+            Vector2::new(start.x, end.y),
+        ]
+    }
+
+    /// Returns the point inside or on the border of the rectangle that is closest to `to`.
+    ///
+    /// Points inside the rectangle are returned unchanged; points outside are clamped to the border.
+    ///
+    /// This method does not exist in Godot and is provided in Rust for convenience.
+    #[inline]
+    pub fn closest_point(self, to: Vector2) -> Vector2 {
+        let rect = self.abs();
+        to.clamp(rect.position, rect.end())
+    }
+
     /// Returns the area of the rectangle.
     #[inline]
     pub fn area(self) -> real {
@@ -308,10 +338,58 @@ impl std::fmt::Display for Rect2 {
 
 #[cfg(test)]
 mod test {
+    use super::*;
+
+    #[test]
+    fn points() {
+        let rect = Rect2::from_components(1.0, 2.0, 3.0, 4.0);
+        let expected = [
+            Vector2::new(1.0, 2.0),
+            Vector2::new(4.0, 2.0),
+            Vector2::new(4.0, 6.0),
+            Vector2::new(1.0, 6.0),
+        ];
+
+        assert_eq!(rect.points(), expected);
+
+        let negative = Rect2::from_components(4.0, 6.0, -3.0, -4.0);
+        assert_eq!(negative.points(), expected);
+    }
+
+    #[test]
+    fn closest_point() {
+        let rect = Rect2::from_components(0.0, 0.0, 10.0, 5.0);
+
+        // Inside: unchanged.
+        assert_eq!(
+            rect.closest_point(Vector2::new(3.0, 4.0)),
+            Vector2::new(3.0, 4.0)
+        );
+
+        // Outside: clamped to edge or corner.
+        assert_eq!(
+            rect.closest_point(Vector2::new(-2.0, 3.0)),
+            Vector2::new(0.0, 3.0)
+        );
+        assert_eq!(
+            rect.closest_point(Vector2::new(12.0, 9.0)),
+            Vector2::new(10.0, 5.0)
+        );
+    }
+
+    #[test]
+    fn expand() {
+        let rect = Rect2::from_components(0.0, 0.0, 2.0, 2.0);
+        let expanded = rect.expand(Vector2::new(5.0, -1.0));
+
+        assert_eq!(expanded, Rect2::from_components(0.0, -1.0, 5.0, 3.0));
+        assert!(expanded.encloses(rect));
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
-        let rect = super::Rect2::default();
+        let rect = Rect2::default();
         let expected_json = "{\"position\":{\"x\":0.0,\"y\":0.0},\"size\":{\"x\":0.0,\"y\":0.0}}";
 
         crate::builtin::test_utils::roundtrip(&rect, expected_json);
@@ -5,18 +5,29 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+#[cfg(since_api = "4.2")]
+use std::cell::RefCell;
 use std::fmt;
+#[cfg(since_api = "4.2")]
+use std::future::Future;
+#[cfg(since_api = "4.2")]
+use std::pin::Pin;
 use std::ptr;
+#[cfg(since_api = "4.2")]
+use std::rc::Rc;
+#[cfg(since_api = "4.2")]
+use std::task;
 
 use godot_ffi as sys;
 
 use crate::builtin::{inner, Array, Callable, Dictionary, StringName, Variant};
+use crate::classes::object::ConnectFlags;
 use crate::classes::Object;
 use crate::global::Error;
 use crate::meta;
 use crate::meta::{FromGodot, GodotType, ToGodot};
 use crate::obj::bounds::DynMemory;
-use crate::obj::{Bounds, Gd, GodotClass, InstanceId};
+use crate::obj::{Bounds, EngineBitfield, Gd, GodotClass, InstanceId};
 use sys::{ffi_methods, GodotFfi};
 
 /// A `Signal` represents a signal of an Object instance in Godot.
@@ -100,6 +111,61 @@ impl Signal {
         object.emit_signal(&self.name(), varargs);
     }
 
+    /// Returns a future that resolves the next time this signal is emitted.
+    ///
+    /// This is the Rust counterpart to GDScript's `await signal`. The signal arguments are discarded; use
+    /// [`await_matching()`][Self::await_matching] to receive them.
+    ///
+    /// The connection is made immediately (with [`ConnectFlags::ONE_SHOT`](crate::classes::object::ConnectFlags::ONE_SHOT)), not on the
+    /// first poll, so emissions between this call and the first poll are not missed. godot-rust does not ship an async runtime; the future
+    /// needs to be driven by an executor on the thread that created it.
+    ///
+    /// Dropping the future before the signal is emitted disconnects it again.
+    #[cfg(since_api = "4.2")]
+    pub fn await_once(&self) -> impl Future<Output = ()> {
+        let state = AwaitState::new_shared();
+
+        let callback_state = state.clone();
+        let callable = Callable::from_local_fn("Signal::await_once", move |args| {
+            AwaitState::resolve(&callback_state, args, None);
+            Ok(Variant::nil())
+        });
+
+        self.connect(&callable, ConnectFlags::ONE_SHOT.ord() as i64);
+
+        let future = SignalFuture::new(state, self.clone(), callable);
+        async move {
+            future.await;
+        }
+    }
+
+    /// Returns a future that resolves with the arguments of the next emission for which `filter` returns `true`.
+    ///
+    /// Emissions rejected by `filter` are ignored; the signal stays connected until an emission is accepted. Connection, execution and
+    /// dropping behave like [`await_once()`][Self::await_once].
+    #[cfg(since_api = "4.2")]
+    pub fn await_matching<F>(&self, mut filter: F) -> SignalFuture
+    where
+        F: FnMut(&[&Variant]) -> bool + 'static,
+    {
+        let state = AwaitState::new_shared();
+
+        let callback_state = state.clone();
+        let signal = self.clone();
+        let callable = Callable::from_local_fn("Signal::await_matching", move |args| {
+            if filter(args) {
+                AwaitState::resolve(&callback_state, args, Some(&signal));
+            }
+
+            Ok(Variant::nil())
+        });
+
+        state.borrow_mut().callable = Some(callable.clone());
+        self.connect(&callable, 0);
+
+        SignalFuture::new(state, self.clone(), callable)
+    }
+
     /// Returns an [`Array`] of connections for this signal.
     ///
     /// Each connection is represented as a Dictionary that contains three entries:
@@ -116,6 +182,22 @@ impl Signal {
             .collect()
     }
 
+    /// Returns the connections of this signal in typed form.
+    ///
+    /// Same information as [`connections()`][Self::connections], with each entry converted to a [`SignalConnection`].
+    #[doc(alias = "get_connections")]
+    pub fn typed_connections(&self) -> Vec<SignalConnection> {
+        self.connections()
+            .iter_shared()
+            .map(|dict| SignalConnection::from_dictionary(&dict))
+            .collect()
+    }
+
+    /// Returns the number of callables connected to this signal.
+    pub fn connection_count(&self) -> usize {
+        self.as_inner().get_connections().len()
+    }
+
     /// Returns the name of the signal.
     pub fn name(&self) -> StringName {
         self.as_inner().get_name()
@@ -162,6 +244,43 @@ impl Signal {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// A single connection of a [`Signal`], as returned by [`Signal::typed_connections()`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct SignalConnection {
+    /// The connected callable.
+    pub callable: Callable,
+
+    /// Flags that the connection was made with.
+    pub flags: ConnectFlags,
+
+    /// Arguments bound to the callable via `Callable::bind()`; they are passed after the signal arguments.
+    pub binds: Vec<Variant>,
+}
+
+impl SignalConnection {
+    /// Converts an entry of [`Signal::connections()`] or [`Object::get_signal_connection_list()`](crate::classes::Object::get_signal_connection_list).
+    ///
+    /// # Panics
+    /// If the dictionary does not have the `callable` and `flags` entries of a connection.
+    pub fn from_dictionary(dict: &Dictionary) -> Self {
+        let callable: Callable = dict.at("callable").to();
+        let flags = ConnectFlags::from_ord(dict.at("flags").to::<i64>() as u64);
+        let binds = callable
+            .as_inner()
+            .get_bound_arguments()
+            .iter_shared()
+            .collect();
+
+        Self {
+            callable,
+            flags,
+            binds,
+        }
+    }
+}
+
 // SAFETY:
 // The `opaque` in `Signal` is just a pair of pointers, and requires no special initialization or cleanup
 // beyond what is done in `from_opaque` and `drop`. So using `*mut Opaque` is safe.
@@ -213,3 +332,220 @@ impl fmt::Display for Signal {
         write!(f, "{}", self.to_variant())
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Await support
+
+/// Future returned by [`Signal::await_matching()`], resolving with the arguments of the accepted emission.
+///
+/// Awaiting several of these at once, and continuing with whichever resolves first, is possible with [`godot_select!`][crate::godot_select].
+///
+/// If the future is dropped before it resolves, the signal connection is removed.
+#[cfg(since_api = "4.2")]
+pub struct SignalFuture {
+    state: Rc<RefCell<AwaitState>>,
+    signal: Signal,
+    callable: Callable,
+}
+
+#[cfg(since_api = "4.2")]
+impl SignalFuture {
+    fn new(state: Rc<RefCell<AwaitState>>, signal: Signal, callable: Callable) -> Self {
+        Self {
+            state,
+            signal,
+            callable,
+        }
+    }
+}
+
+#[cfg(since_api = "4.2")]
+impl Future for SignalFuture {
+    type Output = Vec<Variant>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> task::Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+        match state.args.take() {
+            Some(args) => task::Poll::Ready(args),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                task::Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(since_api = "4.2")]
+impl Drop for SignalFuture {
+    fn drop(&mut self) {
+        // Breaks the reference cycle between state and callable, in case the signal was never emitted.
+        self.state.borrow_mut().callable = None;
+
+        // Object may have been freed meanwhile, which removes all its connections.
+        if self.signal.object().is_some() && self.signal.is_connected(&self.callable) {
+            self.signal.disconnect(&self.callable);
+        }
+    }
+}
+
+/// Shared state between a signal callback and the future awaiting it.
+#[cfg(since_api = "4.2")]
+#[derive(Default)]
+struct AwaitState {
+    args: Option<Vec<Variant>>,
+    waker: Option<task::Waker>,
+
+    /// Connected callable, if it must be disconnected manually. Taken on resolution, which breaks the reference cycle.
+    callable: Option<Callable>,
+}
+
+#[cfg(since_api = "4.2")]
+impl AwaitState {
+    fn new_shared() -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Self::default()))
+    }
+
+    /// Stores the arguments, disconnects the callable from `signal` (if both are present) and wakes the future.
+    ///
+    /// Does nothing if already resolved.
+    fn resolve(state: &RefCell<Self>, args: &[&Variant], signal: Option<&Signal>) {
+        let (waker, callable) = {
+            let mut state = state.borrow_mut();
+            if state.args.is_some() {
+                return;
+            }
+
+            state.args = Some(args.iter().map(|&arg| arg.clone()).collect());
+            (state.waker.take(), state.callable.take())
+        };
+
+        // Disconnect before waking: the executor may poll synchronously and drop the future, which also tries to disconnect.
+        if let (Some(signal), Some(callable)) = (signal, callable) {
+            signal.disconnect(&callable);
+        }
+
+        // Wake outside the borrow, for the same reason.
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Select macro
+
+/// Awaits several futures at once, and runs the arm of the first one that completes.
+///
+/// Each arm has the form `pattern = future => expression`. The futures are polled in the order of the arms, so earlier arms take
+/// precedence if several are ready at the same time. When one completes, its output is matched against `pattern`, and the remaining
+/// futures are dropped. For [`SignalFuture`]s and [`Signal::await_once()`], dropping disconnects them from their signals.
+///
+/// An optional last arm `default => expression` is evaluated if none of the futures is ready on the first poll, instead of waiting.
+///
+/// The macro must be used inside an `async` context; like the futures themselves, it needs to be driven by an executor.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// async fn wait_for_player(timeout: Signal, died: Signal) -> bool {
+///     godot_select! {
+///         () = timeout.await_once() => false,
+///         args = died.await_matching(|_| true) => {
+///             godot_print!("died with {args:?}");
+///             true
+///         },
+///     }
+/// }
+/// ```
+#[cfg(since_api = "4.2")]
+#[macro_export]
+macro_rules! godot_select {
+    // Collects arms as `(pattern, future, body)` groups, until the optional default arm or the end of input.
+    (@parse [$($arms:tt)*] default => $default:expr $(,)?) => {{
+        let __output = $crate::builtin::__select::poll_once($crate::godot_select!(@future $($arms)*)).await;
+
+        match __output {
+            ::std::option::Option::Some(__output) => $crate::godot_select!(@match __output; $($arms)*),
+            ::std::option::Option::None => $default,
+        }
+    }};
+    (@parse [$($arms:tt)*] $pat:pat = $future:expr => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::godot_select!(@parse [$($arms)* ($pat, $future, $body)] $($($rest)*)?)
+    };
+    (@parse [$($arms:tt)*]) => {{
+        let __output = $crate::godot_select!(@future $($arms)*).await;
+        $crate::godot_select!(@match __output; $($arms)*)
+    }};
+
+    // Combines futures into nested Either outputs: a, b, c -> select(a, select(b, c)).
+    (@future ($pat:pat, $future:expr, $body:expr)) => {
+        $future
+    };
+    (@future ($pat:pat, $future:expr, $body:expr) $($rest:tt)+) => {
+        $crate::builtin::__select::select_biased($future, $crate::godot_select!(@future $($rest)+))
+    };
+
+    // Destructures the nested Either outputs in the same order.
+    (@match $output:ident; ($pat:pat, $future:expr, $body:expr)) => {
+        match $output {
+            $pat => $body,
+        }
+    };
+    (@match $output:ident; ($pat:pat, $future:expr, $body:expr) $($rest:tt)+) => {
+        match $output {
+            $crate::builtin::__select::Either::Left($pat) => $body,
+            $crate::builtin::__select::Either::Right(__rest) => $crate::godot_select!(@match __rest; $($rest)+),
+        }
+    };
+
+    // Entry point.
+    ($($arms:tt)+) => {
+        $crate::godot_select!(@parse [] $($arms)+)
+    };
+}
+
+/// Runtime support for [`godot_select!`][crate::godot_select].
+#[cfg(since_api = "4.2")]
+#[doc(hidden)]
+pub mod __select {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::Poll;
+
+    pub enum Either<L, R> {
+        Left(L),
+        Right(R),
+    }
+
+    /// Resolves with the output of whichever future completes first; `a` is polled before `b`.
+    pub async fn select_biased<A, B>(a: A, b: B) -> Either<A::Output, B::Output>
+    where
+        A: Future,
+        B: Future,
+    {
+        let mut a = pin!(a);
+        let mut b = pin!(b);
+
+        std::future::poll_fn(|cx| {
+            if let Poll::Ready(output) = a.as_mut().poll(cx) {
+                return Poll::Ready(Either::Left(output));
+            }
+            if let Poll::Ready(output) = b.as_mut().poll(cx) {
+                return Poll::Ready(Either::Right(output));
+            }
+            Poll::Pending
+        })
+        .await
+    }
+
+    /// Polls `future` exactly once, resolving immediately with its output if ready, or `None` otherwise.
+    pub async fn poll_once<F: Future>(future: F) -> Option<F::Output> {
+        let mut future = pin!(future);
+
+        std::future::poll_fn(|cx| match future.as_mut().poll(cx) {
+            Poll::Ready(output) => Poll::Ready(Some(output)),
+            Poll::Pending => Poll::Ready(None),
+        })
+        .await
+    }
+}
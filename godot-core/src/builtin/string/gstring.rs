@@ -111,6 +111,58 @@ impl GString {
         }
     }
 
+    /// Replaces all occurrences of `from` with `to`.
+    ///
+    /// If `from` is empty, the string is returned unchanged.
+    ///
+    /// _Godot equivalent: `replace`_
+    #[doc(alias = "replace")]
+    pub fn replace_all(&self, from: impl AsArg<GString>, to: impl AsArg<GString>) -> GString {
+        self.as_inner().replace(from, to)
+    }
+
+    /// Replaces the first occurrence of `from` with `to`.
+    ///
+    /// Equivalent to [`replace_n(from, to, 1)`][Self::replace_n].
+    pub fn replace_first(&self, from: impl AsArg<GString>, to: impl AsArg<GString>) -> GString {
+        self.replace_n(from, to, 1)
+    }
+
+    /// Replaces the first `max_count` occurrences of `from` with `to`, scanning from the start.
+    ///
+    /// If `from` is empty or `max_count` is 0, the string is returned unchanged.
+    ///
+    /// Godot's own `replacen` is unrelated: it replaces _all_ occurrences case-insensitively.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let s = GString::from("a-b-c-d");
+    ///
+    /// assert_eq!(s.replace_n("-", "+", 2), GString::from("a+b+c-d"));
+    /// assert_eq!(s.replace_first("-", ""), GString::from("ab-c-d"));
+    /// assert_eq!(s.replace_all("-", ""), GString::from("abcd"));
+    /// ```
+    pub fn replace_n(
+        &self,
+        from: impl AsArg<GString>,
+        to: impl AsArg<GString>,
+        max_count: u32,
+    ) -> GString {
+        meta::arg_into_ref!(from: GString);
+        meta::arg_into_ref!(to: GString);
+
+        if from.chars().is_empty() || max_count == 0 {
+            return self.clone();
+        }
+
+        let replaced =
+            self.to_string()
+                .replacen(&from.to_string(), &to.to_string(), max_count as usize);
+
+        GString::from(replaced)
+    }
+
     ffi_methods! {
         type sys::GDExtensionStringPtr = *mut Self;
 
@@ -21,6 +21,13 @@ use super::{GString, StringName};
 /// Note that Godot ignores any bytes after a null-byte. This means that for instance `"hello, world!"` and `"hello, world!\0 ignored by Godot"`
 /// will be treated as the same string if converted to a `NodePath`.
 ///
+/// # Text representation
+///
+/// Godot stores a `NodePath` as a list of pre-parsed names and subnames, not as contiguous text. This is why there is no borrowing
+/// `as_str()` method: the textual form must be assembled on demand. Formatting with `{}` (via [`Display`][fmt::Display]) does this by
+/// allocating a temporary [`GString`] on every call. Individual path segments are available without assembling the whole path, as
+/// interned [`StringName`]s through [`get_name()`][Self::get_name] and [`get_subname()`][Self::get_subname].
+///
 /// # All string types
 ///
 /// | Intended use case | String type                                |
@@ -41,6 +48,33 @@ impl NodePath {
         Self { opaque }
     }
 
+    /// Creates a path by joining `components` with `/`.
+    ///
+    /// Components are not escaped: they are parsed with the usual `NodePath` syntax after joining. A component `"Body/Sprite2D"` thus
+    /// contributes two names, `"/root"` as the first component makes the path absolute, and `:` starts the property subnames.
+    /// Empty components are skipped, so an empty iterator yields an empty path.
+    ///
+    /// This method does not exist in Godot and is provided in Rust for convenience.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let names = vec![String::from("Level"), String::from("Player")];
+    /// let path = NodePath::from_components_iter(names.iter().map(String::as_str));
+    /// assert_eq!(path, NodePath::from("Level/Player"));
+    /// ```
+    pub fn from_components_iter<'a>(components: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut joined = String::new();
+        for component in components.into_iter().filter(|c| !c.is_empty()) {
+            if !joined.is_empty() {
+                joined.push('/');
+            }
+            joined.push_str(component);
+        }
+
+        Self::from(joined)
+    }
+
     /// Returns the node name at position `index`.
     ///
     /// If you want to get a property name instead, check out [`get_subname()`][Self::get_subname].
@@ -110,6 +144,34 @@ impl NodePath {
             .expect("Godot subname counts are non-negative ints")
     }
 
+    /// Returns an iterator over the node names in the path, in order. Property subnames are not included.
+    ///
+    /// Equivalent to calling [`get_name()`][Self::get_name] for each index up to [`get_name_count()`][Self::get_name_count]. The iterator
+    /// can also be traversed from the back, e.g. `path.names().next_back()` for the last node name.
+    ///
+    /// This method does not exist in Godot and is provided in Rust for convenience.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let path = NodePath::from("../RigidBody2D/Sprite2D:texture");
+    /// let names: Vec<StringName> = path.names().collect();
+    /// assert_eq!(names, ["..", "RigidBody2D", "Sprite2D"].map(StringName::from));
+    /// ```
+    pub fn names(&self) -> impl DoubleEndedIterator<Item = StringName> + ExactSizeIterator + '_ {
+        (0..self.get_name_count()).map(|index| self.get_name(index))
+    }
+
+    /// Returns an iterator over the property subnames in the path, in order. Node names are not included.
+    ///
+    /// Equivalent to calling [`get_subname()`][Self::get_subname] for each index up to [`get_subname_count()`][Self::get_subname_count].
+    /// Like [`names()`][Self::names], the iterator is double-ended.
+    ///
+    /// This method does not exist in Godot and is provided in Rust for convenience.
+    pub fn subnames(&self) -> impl DoubleEndedIterator<Item = StringName> + ExactSizeIterator + '_ {
+        (0..self.get_subname_count()).map(|index| self.get_subname(index))
+    }
+
     /// Returns the total number of names + subnames.
     ///
     /// This method does not exist in Godot and is provided in Rust for convenience.
@@ -117,6 +179,110 @@ impl NodePath {
         self.get_name_count() + self.get_subname_count()
     }
 
+    /// Returns how deep this path reaches into the scene tree, i.e. the number of node names.
+    ///
+    /// Same as [`get_name_count()`][Self::get_name_count]; property subnames are not included. Empty paths have depth 0.
+    ///
+    /// This method does not exist in Godot and is provided in Rust for convenience.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// assert_eq!(NodePath::from("").depth(), 0);
+    /// assert_eq!(NodePath::from("Sprite2D").depth(), 1);
+    /// assert_eq!(NodePath::from("../RigidBody2D/Sprite2D:texture").depth(), 3);
+    /// ```
+    pub fn depth(&self) -> u32 {
+        self.get_name_count() as u32
+    }
+
+    /// Returns `true` if the path consists of exactly one node name and no property subnames.
+    ///
+    /// This method does not exist in Godot and is provided in Rust for convenience.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// assert!(NodePath::from("Sprite2D").is_single_name());
+    /// assert!(!NodePath::from("Body/Sprite2D").is_single_name());
+    /// assert!(!NodePath::from("Sprite2D:texture").is_single_name());
+    /// ```
+    pub fn is_single_name(&self) -> bool {
+        self.get_name_count() == 1 && self.get_subname_count() == 0
+    }
+
+    /// Returns `true` if the path only refers to properties, i.e. has subnames but no node names.
+    ///
+    /// Such paths start with a colon and are resolved relative to the node they are used on.
+    ///
+    /// This method does not exist in Godot and is provided in Rust for convenience.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// assert!(NodePath::from(":position:x").is_property_only());
+    /// assert!(!NodePath::from("Sprite2D:position").is_property_only());
+    /// assert!(!NodePath::from("").is_property_only());
+    /// ```
+    pub fn is_property_only(&self) -> bool {
+        self.get_name_count() == 0 && self.get_subname_count() > 0
+    }
+
+    /// Returns a new path with only the node names, without property subnames.
+    ///
+    /// Absolute paths stay absolute. If there are no subnames, this is the same as `self.clone()`; if there are no node names, the result
+    /// is an empty path.
+    ///
+    /// This method does not exist in Godot and is provided in Rust for convenience.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let path = NodePath::from("Body/Sprite2D:texture:resource_name");
+    /// assert_eq!(path.strip_subnames(), NodePath::from("Body/Sprite2D"));
+    /// ```
+    pub fn strip_subnames(&self) -> NodePath {
+        if self.get_name_count() == 0 {
+            return NodePath::default();
+        }
+        if self.get_subname_count() == 0 {
+            return self.clone();
+        }
+
+        let inner = self.as_inner();
+        let names = inner.get_concatenated_names();
+        if inner.is_absolute() {
+            NodePath::from(format!("/{names}"))
+        } else {
+            NodePath::from(&names)
+        }
+    }
+
+    /// Returns a new path with only the property subnames, without node names.
+    ///
+    /// The result starts with `:` and refers to properties relative to the node it is used on. If there are no node names, this is the
+    /// same as `self.clone()`; if there are no subnames, the result is an empty path.
+    ///
+    /// This method does not exist in Godot and is provided in Rust for convenience.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let path = NodePath::from("Body/Sprite2D:texture:resource_name");
+    /// assert_eq!(path.strip_names(), NodePath::from(":texture:resource_name"));
+    /// ```
+    pub fn strip_names(&self) -> NodePath {
+        if self.get_subname_count() == 0 {
+            return NodePath::default();
+        }
+        if self.get_name_count() == 0 {
+            return self.clone();
+        }
+
+        let subnames = self.as_inner().get_concatenated_subnames();
+        NodePath::from(format!(":{subnames}"))
+    }
+
     /// Returns a 32-bit integer hash value representing the string.
     pub fn hash(&self) -> u32 {
         self.as_inner()
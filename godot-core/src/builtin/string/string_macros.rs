@@ -42,6 +42,13 @@ macro_rules! impl_shared_string_api {
                 self.find_ex(what).done()
             }
 
+            /// Find last occurrence of `what` and return index, or `None` if not found.
+            ///
+            /// Check [`find_ex()`](Self::find_ex) for all custom options.
+            pub fn rfind(&self, what: impl AsArg<GString>) -> Option<usize> {
+                self.find_ex(what).r().done()
+            }
+
             /// Returns a builder for finding substrings, with various configuration options.
             ///
             /// The builder struct offers methods to configure 3 dimensions, which map to different Godot functions in the back:
@@ -71,7 +78,7 @@ macro_rules! impl_shared_string_api {
             /// if found != -1:
             ///     do_sth_with_index(found)
             /// ```
-            #[doc(alias = "findn", alias = "rfind", alias = "rfindn")]
+            #[doc(alias = "findn", alias = "rfindn")]
             pub fn find_ex<'s, 'w>(
                 &'s self,
                 what: impl AsArg<GString> + 'w,
@@ -183,6 +190,32 @@ macro_rules! impl_shared_string_api {
                 self.as_inner().format(array_or_dict, "{_}")
             }
 
+            /// Format a string by replacing `{key}` placeholders with the corresponding values in `values`.
+            ///
+            /// Keys are converted to strings. Placeholders without a matching key are left untouched.
+            ///
+            /// # Example
+            /// ```no_run
+            /// # use godot::prelude::*;
+            #[doc = concat!("let template = ", stringify!($Builtin), "::from(\"{name} has {count} coins\");")]
+            /// let text = template.format_dict(&dict! { "name": "Ferris", "count": 3 });
+            /// assert_eq!(text, GString::from("Ferris has 3 coins"));
+            /// ```
+            ///
+            /// See Godot's [`String.format()`](https://docs.godotengine.org/en/stable/classes/class_string.html#class-string-method-format).
+            pub fn format_dict(&self, values: &$crate::builtin::Dictionary) -> GString {
+                self.format(&$crate::meta::ToGodot::to_variant(values))
+            }
+
+            /// Format a string by replacing `{0}`, `{1}`, ... placeholders with the elements of `values` at that index.
+            ///
+            /// Placeholders with an out-of-bounds index are left untouched.
+            ///
+            /// See Godot's [`String.format()`](https://docs.godotengine.org/en/stable/classes/class_string.html#class-string-method-format).
+            pub fn format_array<T: $crate::meta::ArrayElement>(&self, values: &$crate::builtin::Array<T>) -> GString {
+                self.format(&$crate::meta::ToGodot::to_variant(values))
+            }
+
             /// Format a string using substitutions from an array or dictionary + custom placeholder.
             ///
             /// See Godot's [`String.format()`](https://docs.godotengine.org/en/stable/classes/class_string.html#class-string-method-format).
@@ -219,11 +252,21 @@ macro_rules! impl_shared_string_api {
             }
 
             /// Formats the string representing a number to have an exact number of `digits` _after_ the decimal point.
+            ///
+            /// - Missing decimals are filled with zeros: `"1.5"` becomes `"1.500"` for 3 digits, and `"12"` becomes `"12.00"` for 2.
+            /// - Surplus decimals are **truncated, not rounded**: `"1.2399"` becomes `"1.23"` for 2 digits.
+            /// - With 0 digits, the decimal point is removed as well: `"1.99"` becomes `"1"`.
+            ///
+            /// The string is not validated to be a number; only the part after the first `.` is considered.
             pub fn pad_decimals(&self, digits: usize) -> GString {
                 self.as_inner().pad_decimals(digits as i64)
             }
 
-            /// Formats the string representing a number to have an exact number of `digits` _before_ the decimal point.
+            /// Formats the string representing a number to have at least `digits` digits _before_ the decimal point.
+            ///
+            /// - Zeros are inserted after any leading non-digit characters such as a sign: `"-12.5"` becomes `"-0012.5"` for 4 digits.
+            /// - Longer integer parts are left unchanged, never truncated: `"12345"` stays `"12345"` for 2 digits.
+            /// - Decimals are not affected; see [`pad_decimals()`](Self::pad_decimals) for those.
             pub fn pad_zeros(&self, digits: usize) -> GString {
                 self.as_inner().pad_zeros(digits as i64)
             }
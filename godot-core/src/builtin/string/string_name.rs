@@ -76,6 +76,23 @@ impl StringName {
             .expect("Godot hashes are uint32_t")
     }
 
+    /// Compares the characters of this string name with a Rust string slice, without interning the latter.
+    ///
+    /// Prefer this over `name == StringName::from(s)`, which interns `s` on every comparison. This is an inherent method rather than
+    /// a `PartialEq` impl, so that `name == "jump".into()` keeps inferring `StringName`.
+    pub fn eq_str(&self, other: &str) -> bool {
+        // StringName holds a ref-counted String internally, so this conversion does not copy characters.
+        let string = GString::from(self);
+        string.chars().iter().copied().eq(other.chars())
+    }
+
+    /// Compares the characters of this string name with a `GString`, without interning the latter.
+    ///
+    /// See [`eq_str()`][Self::eq_str] for why this is not a `PartialEq` impl.
+    pub fn eq_gstring(&self, other: &GString) -> bool {
+        GString::from(self) == *other
+    }
+
     meta::declare_arg_method! {
         /// Use as argument for an [`impl AsArg<GString|NodePath>`][crate::meta::AsArg] parameter.
         ///
@@ -127,6 +127,21 @@ impl Transform2D {
         )
     }
 
+    /// Create a new `Transform2D` located at `from`, rotated so that its X axis points towards `target`.
+    ///
+    /// This is the pure-math counterpart to [`Node2D::look_at()`](crate::classes::Node2D::look_at), for an unscaled transform.
+    /// If `target` is equal to `from`, the transform is not rotated.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let transform = Transform2D::looking_at(Vector2::new(1.0, 1.0), Vector2::new(1.0, 5.0));
+    /// assert_eq!(transform.origin, Vector2::new(1.0, 1.0));
+    /// // The X axis now points down (positive Y), towards the target.
+    /// ```
+    pub fn looking_at(from: Vector2, target: Vector2) -> Self {
+        Self::from_angle_origin((target - from).angle(), from)
+    }
+
     /// Unstable, used to simplify codegen. Too many parameters for public API and easy to have off-by-one, `from_cols()` is preferred.
     #[doc(hidden)]
     #[rustfmt::skip]
@@ -627,6 +642,26 @@ mod test {
         assert_eq_approx!(trans.origin, Vector2::new(2.4, 6.8));
     }
 
+    #[test]
+    fn looking_at() {
+        let from = Vector2::new(1.0, 1.0);
+        let trans = Transform2D::looking_at(from, Vector2::new(1.0, 5.0));
+        assert_eq_approx!(trans.origin, from);
+        assert_eq_approx!(trans.a, Vector2::DOWN);
+        assert_eq_approx!(trans.b, Vector2::LEFT);
+
+        // Target is mapped onto the local X axis.
+        let target = Vector2::new(-2.0, 5.0);
+        let trans = Transform2D::looking_at(from, target);
+        let local = trans.affine_inverse() * target;
+        assert_eq_approx!(local, Vector2::new((target - from).length(), 0.0));
+
+        // Same point: no rotation.
+        let trans = Transform2D::looking_at(from, from);
+        assert_eq_approx!(trans.a, Vector2::RIGHT);
+        assert_eq_approx!(trans.b, Vector2::DOWN);
+    }
+
     // Tests translated from Godot.
 
     const DUMMY_TRANSFORM: Transform2D = Transform2D::from_basis_origin(
@@ -8,8 +8,8 @@
 use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
-use crate::builtin::math::{ApproxEq, GlamConv, GlamType};
-use crate::builtin::{real, Aabb, Basis, Plane, Projection, RAffine3, Vector3};
+use crate::builtin::math::{ApproxEq, FloatExt, GlamConv, GlamType};
+use crate::builtin::{real, Aabb, Basis, Plane, Projection, Quaternion, RAffine3, Vector3};
 
 use std::fmt::Display;
 use std::ops::Mul;
@@ -93,6 +93,16 @@ impl Transform3D {
         }
     }
 
+    /// Create a new transform from scale, rotation and translation, applied in this order.
+    ///
+    /// This is the inverse of [`decompose()`][Self::decompose].
+    pub fn from_srt(scale: Vector3, rotation: Quaternion, translation: Vector3) -> Self {
+        Self {
+            basis: Basis::from_quat(rotation) * Basis::from_scale(scale),
+            origin: translation,
+        }
+    }
+
     /// Constructs a `Transform3D` from a `Projection` by trimming the last row of the projection matrix.
     ///
     /// _Godot equivalent: `Transform3D(Projection from)`_
@@ -154,6 +164,39 @@ impl Transform3D {
         }
     }
 
+    /// Splits the transform into scale, rotation and translation, in that order.
+    ///
+    /// Non-uniform scale is supported. A basis with negative determinant (mirroring) is represented by negating all three scale components,
+    /// so that the rotation stays a proper rotation. Shear cannot be represented by scale and rotation; it is discarded from the result.
+    ///
+    /// If the basis is degenerate (an axis of length zero, e.g. a scale animation collapsing it to 0, or linearly dependent axes), no
+    /// rotation can be recovered; the rotation is then [`Quaternion::IDENTITY`]. Small but non-zero scales are not degenerate.
+    ///
+    /// [`from_srt()`][Self::from_srt] composes the parts back into a transform.
+    pub fn decompose(&self) -> (Vector3, Quaternion, Vector3) {
+        let scale = self.basis.scale();
+        let degenerate = (scale, Quaternion::IDENTITY, self.origin);
+
+        // Normalize the axes first, so that the degeneracy check does not depend on the magnitude of the scale.
+        let cols = self.basis.to_cols();
+        if cols.iter().any(|col| col.length_squared() == 0.0) {
+            return degenerate;
+        }
+
+        let [a, b, c] = cols.map(Vector3::normalized);
+        let normalized = Basis::from_cols(a, b, c);
+        if normalized.determinant().is_zero_approx() {
+            return degenerate;
+        }
+
+        let mut rotation = normalized.orthonormalized();
+        if rotation.determinant() < 0.0 {
+            rotation = rotation.scaled(Vector3::splat(-1.0));
+        }
+
+        (scale, rotation.to_quat().normalized(), self.origin)
+    }
+
     /// Returns true if this transform is finite by calling `is_finite` on the
     /// basis and origin.
     pub fn is_finite(&self) -> bool {
@@ -185,7 +228,8 @@ impl Transform3D {
     /// with a corresponding rotation transform `R` from the left, i.e., `R * X`.
     /// This can be seen as transforming with respect to the global/parent frame.
     ///
-    /// _Godot equivalent: `Transform2D.rotated()`_
+    /// _Godot equivalent: `Transform3D.rotated()`_
+    #[doc(alias = "rotate")]
     #[must_use]
     pub fn rotated(&self, axis: Vector3, angle: real) -> Self {
         let rotation = Basis::from_axis_angle(axis, angle);
@@ -194,12 +238,13 @@ impl Transform3D {
             origin: rotation * self.origin,
         }
     }
+
     /// Returns a copy of the transform rotated by the given `angle` (in radians).
     /// This method is an optimized version of multiplying the given transform `X`
     /// with a corresponding rotation transform `R` from the right, i.e., `X * R`.
     /// This can be seen as transforming with respect to the local frame.
     ///
-    /// _Godot equivalent: `Transform2D.rotated_local()`_
+    /// _Godot equivalent: `Transform3D.rotated_local()`_
     #[must_use]
     pub fn rotated_local(&self, axis: Vector3, angle: real) -> Self {
         Self {
@@ -213,7 +258,8 @@ impl Transform3D {
     /// with a corresponding scaling transform `S` from the left, i.e., `S * X`.
     /// This can be seen as transforming with respect to the global/parent frame.
     ///
-    /// _Godot equivalent: `Transform2D.scaled()`_
+    /// _Godot equivalent: `Transform3D.scaled()`_
+    #[doc(alias = "scale")]
     #[must_use]
     pub fn scaled(&self, scale: Vector3) -> Self {
         Self {
@@ -227,7 +273,7 @@ impl Transform3D {
     /// with a corresponding scaling transform `S` from the right, i.e., `X * S`.
     /// This can be seen as transforming with respect to the local frame.
     ///
-    /// _Godot equivalent: `Transform2D.scaled_local()`_
+    /// _Godot equivalent: `Transform3D.scaled_local()`_
     #[must_use]
     pub fn scaled_local(&self, scale: Vector3) -> Self {
         Self {
@@ -241,7 +287,17 @@ impl Transform3D {
     /// with a corresponding translation transform `T` from the left, i.e., `T * X`.
     /// This can be seen as transforming with respect to the global/parent frame.
     ///
-    /// _Godot equivalent: `Transform2D.translated()`_
+    /// Since all these methods return a new transform, they can be chained:
+    /// ```
+    /// # use godot::builtin::{Transform3D, Vector3};
+    /// # use godot::builtin::real_consts::FRAC_PI_2;
+    /// let transform = Transform3D::IDENTITY
+    ///     .translated(Vector3::new(1.0, 0.0, 0.0))
+    ///     .rotated(Vector3::UP, FRAC_PI_2);
+    /// ```
+    ///
+    /// _Godot equivalent: `Transform3D.translated()`_
+    #[doc(alias = "translate")]
     #[must_use]
     pub fn translated(&self, offset: Vector3) -> Self {
         Self {
@@ -255,7 +311,7 @@ impl Transform3D {
     /// with a corresponding translation transform `T` from the right, i.e., `X * T`.
     /// This can be seen as transforming with respect to the local frame.
     ///
-    /// _Godot equivalent: `Transform2D.translated()`_
+    /// _Godot equivalent: `Transform3D.translated_local()`_
     #[must_use]
     pub fn translated_local(&self, offset: Vector3) -> Self {
         Self {
@@ -399,6 +455,7 @@ crate::meta::impl_godot_as_self!(Transform3D);
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::assert_eq_approx;
 
     // Tests translated from Godot.
 
@@ -430,6 +487,22 @@ mod test {
         );
     }
 
+    #[test]
+    fn chained_builders() {
+        use crate::builtin::real_consts::FRAC_PI_2;
+
+        let transform = Transform3D::IDENTITY
+            .translated(Vector3::new(1.0, 0.0, 0.0))
+            .rotated(Vector3::UP, FRAC_PI_2)
+            .scaled(Vector3::splat(2.0));
+
+        assert_eq_approx!(transform.origin, Vector3::new(0.0, 0.0, -2.0));
+        assert_eq_approx!(
+            transform * Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, -4.0)
+        );
+    }
+
     #[test]
     fn scaling() {
         let scaling = Vector3::new(1.0, 2.0, 3.0);
@@ -466,6 +539,53 @@ mod test {
         );
     }
 
+    #[test]
+    fn decompose_roundtrip() {
+        let rotation = Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalized(), 1.0);
+        let translation = Vector3::new(10.0, -11.0, 12.0);
+
+        for scale in [
+            Vector3::ONE,
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(-0.5, -2.0, -4.0),
+            Vector3::splat(0.02),
+            Vector3::new(0.01, 0.02, 0.03),
+        ] {
+            let transform = Transform3D::from_srt(scale, rotation, translation);
+            let (s, r, t) = transform.decompose();
+
+            assert_eq_approx!(s, scale);
+            assert_eq_approx!(Basis::from_quat(r), Basis::from_quat(rotation));
+            assert_eq_approx!(t, translation);
+            assert_eq_approx!(Transform3D::from_srt(s, r, t), transform);
+        }
+    }
+
+    #[test]
+    fn decompose_mirrored() {
+        // Single flipped axis; the rotation must remain proper.
+        let transform = Transform3D::FLIP_X.translated(Vector3::new(1.0, 2.0, 3.0));
+        let (s, r, t) = transform.decompose();
+
+        assert_eq_approx!(s, Vector3::splat(-1.0));
+        assert_eq_approx!(Basis::from_quat(r).determinant(), 1.0);
+        assert_eq_approx!(Transform3D::from_srt(s, r, t), transform);
+    }
+
+    #[test]
+    fn decompose_degenerate() {
+        let translation = Vector3::new(1.0, 2.0, 3.0);
+
+        for scale in [Vector3::ZERO, Vector3::new(1.0, 0.0, 2.0)] {
+            let transform = Transform3D::from_srt(scale, Quaternion::IDENTITY, translation);
+            let (s, r, t) = transform.decompose();
+
+            assert_eq_approx!(s, scale);
+            assert_eq!(r, Quaternion::IDENTITY);
+            assert_eq_approx!(t, translation);
+        }
+    }
+
     #[test]
     fn finite_number_checks() {
         let y = Vector3::new(0.0, 1.0, 2.0);
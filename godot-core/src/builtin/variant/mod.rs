@@ -7,7 +7,8 @@
 
 use crate::arg_into_ref;
 use crate::builtin::{
-    GString, StringName, VariantArray, VariantDispatch, VariantOperator, VariantType,
+    real, Color, Dictionary, GString, RealConv, StringName, VariantArray, VariantDispatch,
+    VariantOperator, VariantType, Vector2, Vector3, Vector4,
 };
 use crate::meta::error::ConvertError;
 use crate::meta::{ArrayElement, AsArg, FromGodot, ToGodot};
@@ -69,6 +70,36 @@ impl Variant {
         T::try_from_variant(self)
     }
 
+    /// Convert to type `T` by consuming the variant, returning `Err` on failure.
+    ///
+    /// Convenience for call chains that no longer need the variant; equivalent to [`try_to()`][Self::try_to]. No value is moved out of
+    /// the variant. Strings and containers are ref-counted, so the conversion does not copy their contents either way.
+    pub fn into_typed<T: FromGodot>(self) -> Result<T, ConvertError> {
+        self.try_to()
+    }
+
+    /// Checks whether the variant can be converted to type `T`.
+    ///
+    /// Equivalent to `self.try_to::<T>().is_ok()`, and thus follows the same conversion rules as [`try_to()`][Self::try_to]. For example,
+    /// an `INT` variant is not convertible to `f32`. If you only need the type tag, compare [`get_type()`][Self::get_type] instead, which is
+    /// cheaper.
+    pub fn is<T: FromGodot>(&self) -> bool {
+        self.try_to::<T>().is_ok()
+    }
+
+    /// Checks whether the variant holds a string type, i.e. `STRING` or `STRING_NAME`.
+    pub fn is_string(&self) -> bool {
+        matches!(
+            self.get_type(),
+            VariantType::STRING | VariantType::STRING_NAME
+        )
+    }
+
+    /// Checks whether the variant holds a number, i.e. `INT` or `FLOAT`.
+    pub fn is_numeric(&self) -> bool {
+        matches!(self.get_type(), VariantType::INT | VariantType::FLOAT)
+    }
+
     /// Checks whether the variant is empty (`null` value in GDScript).
     ///
     /// See also [`get_type()`][Self::get_type].
@@ -82,6 +113,7 @@ impl Variant {
     /// If this variant holds a type `Object` but no instance (represented as a null object pointer), then `Nil` will be returned for
     /// consistency. This may deviate from Godot behavior -- for example, calling [`Node::get_node_or_null()`][crate::classes::Node::get_node_or_null]
     ///  with an invalid path returns a variant that has type `Object` but acts like `Nil` for all practical purposes.
+    #[doc(alias = "discriminant")]
     pub fn get_type(&self) -> VariantType {
         let sys_type = self.sys_type();
 
@@ -190,6 +222,42 @@ impl Variant {
         }
     }
 
+    /// Compares two variants, allowing floating-point components to differ by at most `epsilon`.
+    ///
+    /// Floats are compared directly; `Vector2`, `Vector3`, `Vector4` and `Color` are compared component-wise. All other types fall back
+    /// to exact comparison with `==`. Variants of different types are never equal, even where Godot's `==` would consider them so
+    /// (e.g. `1.0` and `1`).
+    ///
+    /// Useful in tests, where values computed by physics or animation are subject to rounding errors.
+    pub fn approx_eq(&self, other: &Variant, epsilon: f64) -> bool {
+        let close = |a: f64, b: f64| (a - b).abs() <= epsilon;
+        let all_close =
+            |a: &[real], b: &[real]| a.iter().zip(b).all(|(a, b)| close(a.as_f64(), b.as_f64()));
+
+        match (self.get_type(), other.get_type()) {
+            (VariantType::FLOAT, VariantType::FLOAT) => close(self.to(), other.to()),
+            (VariantType::VECTOR2, VariantType::VECTOR2) => {
+                let (a, b) = (self.to::<Vector2>(), other.to::<Vector2>());
+                all_close(&[a.x, a.y], &[b.x, b.y])
+            }
+            (VariantType::VECTOR3, VariantType::VECTOR3) => {
+                let (a, b) = (self.to::<Vector3>(), other.to::<Vector3>());
+                all_close(&[a.x, a.y, a.z], &[b.x, b.y, b.z])
+            }
+            (VariantType::VECTOR4, VariantType::VECTOR4) => {
+                let (a, b) = (self.to::<Vector4>(), other.to::<Vector4>());
+                all_close(&[a.x, a.y, a.z, a.w], &[b.x, b.y, b.z, b.w])
+            }
+            (VariantType::COLOR, VariantType::COLOR) => {
+                let (a, b) = (self.to::<Color>(), other.to::<Color>());
+                [(a.r, b.r), (a.g, b.g), (a.b, b.b), (a.a, b.a)]
+                    .into_iter()
+                    .all(|(a, b)| close(f64::from(a), f64::from(b)))
+            }
+            (lhs, rhs) => lhs == rhs && self == other,
+        }
+    }
+
     pub(crate) fn sys_type(&self) -> sys::GDExtensionVariantType {
         unsafe {
             let ty: sys::GDExtensionVariantType = interface_fn!(variant_get_type)(self.var_sys());
@@ -231,6 +299,76 @@ impl Variant {
         unsafe { interface_fn!(variant_booleanize)(self.var_sys()) != 0 }
     }
 
+    /// Returns a deep copy of the variant, recursively duplicating nested dictionaries and arrays.
+    ///
+    /// [`Clone`] on a variant holding a `Dictionary` or `Array` only copies the reference, so mutations through the clone are visible in
+    /// the original. This method instead duplicates all nested containers, producing a fully independent value. Other types, including
+    /// objects, are cloned as usual.
+    pub fn deep_clone(&self) -> Variant {
+        match self.get_type() {
+            // Duplicate through the variant itself, so that typed arrays keep their element type.
+            VariantType::DICTIONARY | VariantType::ARRAY => {
+                self.call("duplicate", &[true.to_variant()])
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Looks up a value nested inside dictionaries and arrays, following `keys` one level at a time.
+    ///
+    /// Dictionaries are indexed by key, arrays by integer index. An empty `keys` slice returns a copy of `self`.
+    ///
+    /// Returns `None` if any step fails. Use [`try_get_path()`][Self::try_get_path] to tell a missing key apart from an invalid structure.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # let data = Variant::nil();
+    /// // Equivalent to data["player"]["stats"]["hp"] in GDScript.
+    /// let hp = data.get_path(&["player".to_variant(), "stats".to_variant(), "hp".to_variant()]);
+    /// ```
+    pub fn get_path(&self, keys: &[Variant]) -> Option<Variant> {
+        self.try_get_path(keys).ok().flatten()
+    }
+
+    /// Looks up a value nested inside dictionaries and arrays, following `keys` one level at a time.
+    ///
+    /// Like [`get_path()`][Self::get_path], but distinguishes the two ways a lookup can fail:
+    /// - `Ok(None)` if a dictionary has no such key, or an array index is out of bounds or not an integer.
+    /// - `Err` if an intermediate value is neither a dictionary nor an array. The error's [`value()`][ConvertError::value] holds that value.
+    pub fn try_get_path(&self, keys: &[Variant]) -> Result<Option<Variant>, ConvertError> {
+        let mut current = self.clone();
+
+        for (depth, key) in keys.iter().enumerate() {
+            let next = match current.get_type() {
+                VariantType::DICTIONARY => current.to::<Dictionary>().get(key.clone()),
+                VariantType::ARRAY => {
+                    // Index through the variant rather than converting to `VariantArray`, which would reject typed arrays.
+                    let len = current.call("size", &[]).to::<i64>();
+                    key.try_to::<i64>()
+                        .ok()
+                        .filter(|index| (0..len).contains(index))
+                        .map(|index| current.call("get", &[index.to_variant()]))
+                }
+                other => {
+                    return Err(ConvertError::with_error_value(
+                        format!(
+                            "value at depth {depth} is {other:?}, expected dictionary or array"
+                        ),
+                        current,
+                    ));
+                }
+            };
+
+            match next {
+                Some(value) => current = value,
+                None => return Ok(None),
+            }
+        }
+
+        Ok(Some(current))
+    }
+
     // Conversions from/to Godot C++ `Variant*` pointers
     ffi_methods! {
         type sys::GDExtensionVariantPtr = *mut Self;
@@ -77,16 +77,46 @@ impl Vector2 {
         v.cast_float()
     }
 
+    /// Converts to a [`Vector2i`], truncating each component towards zero; same as [`cast_int()`][Self::cast_int].
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// assert_eq!(Vector2::new(2.7, -2.5).to_vector2i(), Vector2i::new(2, -2));
+    /// ```
+    #[inline]
+    pub const fn to_vector2i(self) -> Vector2i {
+        self.cast_int()
+    }
+
+    /// Converts to a [`Vector2i`], rounding each component to the nearest integer (halfway cases away from zero).
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// assert_eq!(Vector2::new(2.7, -2.5).rounded_to_vector2i(), Vector2i::new(3, -3));
+    /// ```
+    #[inline]
+    pub fn rounded_to_vector2i(self) -> Vector2i {
+        self.round().cast_int()
+    }
+
     /// Creates a unit Vector2 rotated to the given `angle` in radians. This is equivalent to doing `Vector2::new(angle.cos(), angle.sin())`
     /// or `Vector2::RIGHT.rotated(angle)`.
     ///
+    /// The inverse operation is [`angle()`][Self::angle].
+    ///
     /// ```no_run
     /// use godot::prelude::*;
     ///
     /// let a = Vector2::from_angle(0.0);                       // (1.0, 0.0)
     /// let b = Vector2::new(1.0, 0.0).angle();                 // 0.0
     /// let c = Vector2::from_angle(real_consts::PI / 2.0);     // (0.0, 1.0)
+    ///
+    /// // Round trip for angles in (-PI, PI].
+    /// let direction = Vector2::from_angle(1.25);
+    /// assert!((direction.angle() - 1.25).abs() < 1e-6);
     /// ```
+    ///
+    /// _Godot equivalent: `Vector2.from_angle()`_
     #[inline]
     pub fn from_angle(angle: real) -> Self {
         Self::from_glam(RVec2::from_angle(angle))
@@ -99,7 +129,10 @@ impl Vector2 {
     ///
     /// [Illustration of the returned angle.](https://raw.githubusercontent.com/godotengine/godot-docs/master/img/vector2_angle.png)
     ///
-    /// Equivalent to the result of `y.atan2(x)`.
+    /// Equivalent to the result of `y.atan2(x)`. The inverse operation is [`from_angle()`][Self::from_angle].
+    ///
+    /// _Godot equivalent: `Vector2.angle()`_
+    #[doc(alias = "to_angle")]
     #[inline]
     pub fn angle(self) -> real {
         self.y.atan2(self.x)
@@ -215,9 +248,17 @@ impl GlamType for RVec2 {
 #[cfg(test)]
 mod test {
     use crate::assert_eq_approx;
+    use crate::builtin::real_consts::{FRAC_PI_2, PI};
 
     use super::*;
 
+    #[test]
+    fn to_vector2i() {
+        let v = Vector2::new(2.7, -2.5);
+        assert_eq!(v.to_vector2i(), Vector2i::new(2, -2));
+        assert_eq!(v.rounded_to_vector2i(), Vector2i::new(3, -3));
+    }
+
     #[test]
     fn coord_min_max() {
         let a = Vector2::new(1.2, 3.4);
@@ -227,6 +268,23 @@ mod test {
         assert_eq_approx!(a.coord_max(b), Vector2::new(1.2, 5.6));
     }
 
+    #[test]
+    fn angle_roundtrip() {
+        assert_eq!(Vector2::from_angle(0.0), Vector2::RIGHT);
+        assert_eq!(Vector2::DOWN.angle(), FRAC_PI_2);
+        assert_eq!(Vector2::LEFT.angle(), PI);
+        assert_eq_approx!(Vector2::from_angle(FRAC_PI_2), Vector2::DOWN);
+
+        for angle in [-3.0, -1.5, -0.25, 0.0, 0.5, 2.0, 3.0] {
+            let direction = Vector2::from_angle(angle);
+            assert_eq_approx!(direction.length(), 1.0);
+            assert_eq_approx!(direction.angle(), angle);
+        }
+
+        // Length does not affect the angle.
+        assert_eq_approx!((Vector2::from_angle(0.75) * 5.0).angle(), 0.75);
+    }
+
     #[test]
     fn sign() {
         let vector = Vector2::new(0.2, -0.5);
@@ -75,6 +75,30 @@ impl Vector2i {
         v.cast_int()
     }
 
+    /// Converts to a [`Vector2`] with the same components; same as [`cast_float()`][Self::cast_float].
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// assert_eq!(Vector2i::new(3, -4).to_vector2(), Vector2::new(3.0, -4.0));
+    /// ```
+    #[inline]
+    pub const fn to_vector2(self) -> Vector2 {
+        self.cast_float()
+    }
+
+    /// Converts to a [`Vector2`], with each component multiplied by `scale`.
+    ///
+    /// Useful to convert grid coordinates (e.g. tile positions) to world positions.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// assert_eq!(Vector2i::new(3, -4).to_vector2_scaled(16.0), Vector2::new(48.0, -64.0));
+    /// ```
+    #[inline]
+    pub fn to_vector2_scaled(self, scale: real) -> Vector2 {
+        self.cast_float() * scale
+    }
+
     /// Converts `self` to the corresponding [`real`] `glam` type.
     #[doc(hidden)]
     #[inline]
@@ -133,6 +157,13 @@ impl GlamConv for Vector2i {
 mod test {
     use super::*;
 
+    #[test]
+    fn to_vector2() {
+        let v = Vector2i::new(3, -4);
+        assert_eq!(v.to_vector2(), Vector2::new(3.0, -4.0));
+        assert_eq!(v.to_vector2_scaled(0.5), Vector2::new(1.5, -2.0));
+    }
+
     #[test]
     fn coord_min_max() {
         let a = Vector2i::new(1, 3);
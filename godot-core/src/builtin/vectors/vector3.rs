@@ -99,6 +99,28 @@ impl Vector3 {
         v.cast_float()
     }
 
+    /// Converts to a [`Vector3i`], truncating each component towards zero; same as [`cast_int()`][Self::cast_int].
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// assert_eq!(Vector3::new(2.7, -2.5, 0.5).to_vector3i(), Vector3i::new(2, -2, 0));
+    /// ```
+    #[inline]
+    pub const fn to_vector3i(self) -> Vector3i {
+        self.cast_int()
+    }
+
+    /// Converts to a [`Vector3i`], rounding each component to the nearest integer (halfway cases away from zero).
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// assert_eq!(Vector3::new(2.7, -2.5, 0.5).rounded_to_vector3i(), Vector3i::new(3, -3, 1));
+    /// ```
+    #[inline]
+    pub fn rounded_to_vector3i(self) -> Vector3i {
+        self.round().cast_int()
+    }
+
     #[doc(hidden)]
     #[inline]
     pub fn as_inner(&self) -> inner::InnerVector3 {
@@ -302,6 +324,39 @@ mod test {
     use crate::builtin::math::assert_eq_approx;
     use crate::builtin::real_consts::{SQRT_2, TAU};
 
+    #[test]
+    fn to_vector3i() {
+        let v = Vector3::new(2.7, -2.5, 0.4);
+        assert_eq!(v.to_vector3i(), Vector3i::new(2, -2, 0));
+        assert_eq!(v.rounded_to_vector3i(), Vector3i::new(3, -3, 0));
+    }
+
+    #[test]
+    fn bezier() {
+        // Evenly spaced, collinear control points describe a straight line traversed at constant speed.
+        let start = Vector3::ZERO;
+        let control_1 = Vector3::new(1.0, 2.0, -1.0);
+        let control_2 = control_1 * 2.0;
+        let end = control_1 * 3.0;
+
+        assert_eq!(
+            start.bezier_interpolate(control_1, control_2, end, 0.0),
+            start
+        );
+        assert_eq!(
+            start.bezier_interpolate(control_1, control_2, end, 1.0),
+            end
+        );
+        assert_eq_approx!(
+            start.bezier_interpolate(control_1, control_2, end, 0.5),
+            control_1 * 1.5
+        );
+        assert_eq_approx!(
+            start.bezier_derivative(control_1, control_2, end, 0.25),
+            control_1 * 3.0
+        );
+    }
+
     // Translated from Godot
     #[test]
     #[allow(clippy::excessive_precision)]
@@ -78,6 +78,30 @@ impl Vector3i {
         v.cast_int()
     }
 
+    /// Converts to a [`Vector3`] with the same components; same as [`cast_float()`][Self::cast_float].
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// assert_eq!(Vector3i::new(3, -4, 5).to_vector3(), Vector3::new(3.0, -4.0, 5.0));
+    /// ```
+    #[inline]
+    pub const fn to_vector3(self) -> Vector3 {
+        self.cast_float()
+    }
+
+    /// Converts to a [`Vector3`], with each component multiplied by `scale`.
+    ///
+    /// Useful to convert grid coordinates (e.g. tile positions) to world positions.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// assert_eq!(Vector3i::new(3, -4, 5).to_vector3_scaled(16.0), Vector3::new(48.0, -64.0, 80.0));
+    /// ```
+    #[inline]
+    pub fn to_vector3_scaled(self, scale: real) -> Vector3 {
+        self.cast_float() * scale
+    }
+
     inline_impl_integer_vector_fns!(Vector3, x, y, z);
 
     /// Converts `self` to the corresponding [`real`] `glam` type.
@@ -137,6 +161,13 @@ impl GlamConv for Vector3i {
 mod test {
     use super::*;
 
+    #[test]
+    fn to_vector3() {
+        let v = Vector3i::new(3, -4, 5);
+        assert_eq!(v.to_vector3(), Vector3::new(3.0, -4.0, 5.0));
+        assert_eq!(v.to_vector3_scaled(0.5), Vector3::new(1.5, -2.0, 2.5));
+    }
+
     #[test]
     fn coord_min_max() {
         let a = Vector3i::new(1, 3, 5);
@@ -758,6 +758,9 @@ macro_rules! impl_float_vector_fns {
 
             /// A new vector with each component snapped to the closest multiple of the corresponding
             /// component in `step`.
+            ///
+            /// Halfway values are rounded up (towards positive infinity). Components whose `step` is `0.0` are left unchanged,
+            /// which allows snapping only along some axes.
             #[inline]
             pub fn snapped(self, step: Self) -> Self {
                 Self::new(
@@ -1012,6 +1015,25 @@ macro_rules! impl_vector2_vector3_fns {
 
             /// Returns the point at the given `t` on the [Bézier](https://en.wikipedia.org/wiki/B%C3%A9zier_curve)
             /// curve defined by this vector and the given `control_1`, `control_2`, and `end` points.
+            ///
+            /// `t` ranges from 0 (returns `self`) to 1 (returns `end`). The curve generally does not pass through the control points.
+            /// Use [`bezier_derivative()`][Self::bezier_derivative] for the tangent at the same `t`.
+            ///
+            /// Sampling a cubic Bézier path at evenly spaced parameters:
+            /// ```
+            #[doc = concat!("# use godot::builtin::{real, ", stringify!($Vector), "};")]
+            #[doc = concat!("let start = ", stringify!($Vector), "::ZERO;")]
+            #[doc = concat!("let control_1 = ", stringify!($Vector), "::splat(1.0);")]
+            #[doc = concat!("let control_2 = ", stringify!($Vector), "::splat(2.0);")]
+            #[doc = concat!("let end = ", stringify!($Vector), "::splat(3.0);")]
+            ///
+            /// let samples: Vec<_> = (0..=10)
+            ///     .map(|i| start.bezier_interpolate(control_1, control_2, end, i as real / 10.0))
+            ///     .collect();
+            ///
+            /// assert_eq!(samples[0], start);
+            /// assert_eq!(samples[10], end);
+            /// ```
             #[inline]
             pub fn bezier_interpolate(self, control_1: Self, control_2: Self, end: Self, t: real) -> Self {
                 Self::new(
@@ -1041,6 +1063,9 @@ macro_rules! impl_vector2_vector3_fns {
             }
 
             /// Returns a new vector moved toward `to` by the fixed `delta` amount. Will not go past the final value.
+            ///
+            /// The movement is along the straight line between `self` and `to`, so `delta` is a distance, not a per-component step.
+            /// If the distance to `to` is at most `delta`, `to` itself is returned (no overshooting).
             #[inline]
             pub fn move_toward(self, to: Self, delta: real) -> Self {
                 Self::from_glam(self.to_glam().move_towards(to.to_glam(), delta))
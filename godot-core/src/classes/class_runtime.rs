@@ -47,6 +47,12 @@ pub(crate) fn display_string<T: GodotClass>(
     obj: &Gd<T>,
     f: &mut std::fmt::Formatter<'_>,
 ) -> std::fmt::Result {
+    // Godot itself prints "<Freed Object>"; keep the ID, as it helps to correlate log messages.
+    if !obj.is_instance_valid() {
+        let id = obj.instance_id_unchecked();
+        return write!(f, "<Freed Object#{id}>");
+    }
+
     let string: GString = obj.raw.as_object().to_string();
     <GString as std::fmt::Display>::fmt(&string, f)
 }
@@ -0,0 +1,39 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Vector2, Vector3};
+use crate::classes::Camera3D;
+
+/// Manual extensions for the `Camera3D` class.
+impl Camera3D {
+    /// Returns the screen position of `world_pos`, if it is in front of the camera and inside the visible viewport area.
+    ///
+    /// Combines [`is_position_behind()`][Self::is_position_behind] and [`unproject_position()`][Self::unproject_position] with a bounds
+    /// check. `None` means the position is not on screen, e.g. to hide UI elements following a 3D object. Also returns `None`, without
+    /// querying the camera, if it is not inside the scene tree.
+    ///
+    /// Unlike [`is_position_in_frustum()`][Self::is_position_in_frustum], this does not consider the far plane.
+    #[doc(alias = "project_to_screen")]
+    pub fn world_to_screen(&self, world_pos: Vector3) -> Option<Vector2> {
+        // Outside the tree, Godot logs errors for the projection methods below.
+        if !self.is_inside_tree() || self.is_position_behind(world_pos) {
+            return None;
+        }
+
+        let visible_rect = self.get_viewport()?.get_visible_rect();
+        let screen_pos = self.unproject_position(world_pos);
+
+        visible_rect.has_point(screen_pos).then_some(screen_pos)
+    }
+
+    /// Returns `true` if `world_pos` is in front of the camera and inside the visible viewport area.
+    ///
+    /// See [`world_to_screen()`][Self::world_to_screen] for details.
+    pub fn is_position_visible(&self, world_pos: Vector3) -> bool {
+        self.world_to_screen(world_pos).is_some()
+    }
+}
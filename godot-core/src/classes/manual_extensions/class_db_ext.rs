@@ -0,0 +1,35 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::StringName;
+use crate::classes::ClassDb;
+use crate::meta::AsArg;
+use crate::tools::{ClassDbMethod, ClassDbProperty};
+
+/// Manual extensions for the `ClassDB` class.
+impl ClassDb {
+    /// Returns typed descriptions of all methods of `class`, including inherited ones.
+    ///
+    /// Typed counterpart to [`class_get_method_list()`][Self::class_get_method_list]. Returns an empty list if the class does not exist.
+    pub fn class_methods(&self, class: impl AsArg<StringName>) -> Vec<ClassDbMethod> {
+        self.class_get_method_list(class)
+            .iter_shared()
+            .map(|method| ClassDbMethod::from_dictionary(&method))
+            .collect()
+    }
+
+    /// Returns typed descriptions of all properties of `class`, including inherited ones.
+    ///
+    /// Typed counterpart to [`class_get_property_list()`][Self::class_get_property_list]. The list also contains entries for
+    /// property groups and categories, which can be told apart by their [`usage`][ClassDbProperty::usage].
+    pub fn class_properties(&self, class: impl AsArg<StringName>) -> Vec<ClassDbProperty> {
+        self.class_get_property_list(class)
+            .iter_shared()
+            .map(|property| ClassDbProperty::from_dictionary(&property))
+            .collect()
+    }
+}
@@ -0,0 +1,47 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::real;
+use crate::classes::Engine;
+
+/// Manual extensions for the `Engine` class.
+///
+/// Shorter names for the timing getters commonly needed in frame-rate-dependent logic. The time scale is set via
+/// [`set_time_scale()`][Self::set_time_scale].
+impl Engine {
+    /// Frames per second, as measured over the last second.
+    ///
+    /// _Godot equivalent: `get_frames_per_second`_
+    #[doc(alias = "get_frames_per_second")]
+    pub fn current_fps(&self) -> f64 {
+        self.get_frames_per_second()
+    }
+
+    /// Number of process frames since the engine started, i.e. how many times `_process()` has been invoked.
+    ///
+    /// _Godot equivalent: `get_process_frames`_
+    #[doc(alias = "get_process_frames")]
+    pub fn frame_count(&self) -> u64 {
+        self.get_process_frames()
+    }
+
+    /// Number of physics frames since the engine started, i.e. how many times `_physics_process()` has been invoked.
+    ///
+    /// _Godot equivalent: `get_physics_frames`_
+    #[doc(alias = "get_physics_frames")]
+    pub fn physics_frames(&self) -> u64 {
+        self.get_physics_frames()
+    }
+
+    /// Speed multiplier for the game clock; `1.0` is real time.
+    ///
+    /// _Godot equivalent: `get_time_scale`_
+    #[doc(alias = "get_time_scale")]
+    pub fn time_scale(&self) -> f64 {
+        self.get_time_scale()
+    }
+}
@@ -0,0 +1,30 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Hand-written methods on generated classes, one module per class.
+
+mod camera_3d_ext;
+mod class_db_ext;
+mod engine_ext;
+mod node_3d_ext;
+mod node_ext;
+mod object_ext;
+mod packed_scene_ext;
+#[cfg(feature = "codegen-full")]
+mod ray_cast_3d_ext;
+#[cfg(feature = "codegen-full")]
+mod rigid_body_3d_ext;
+mod scene_tree_ext;
+mod scene_tree_timer_ext;
+mod viewport_ext;
+#[cfg(feature = "codegen-full")]
+mod visual_instance_3d_ext;
+
+pub use node_3d_ext::GlobalTransformMut;
+#[cfg(feature = "codegen-full")]
+pub use rigid_body_3d_ext::PhysicsImpulseBuilder;
+pub use scene_tree_ext::{SceneTreeEvent, SceneTreeSignal};
@@ -0,0 +1,103 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#[cfg(feature = "codegen-full")]
+use crate::builtin::Aabb;
+use crate::builtin::{real, Basis, NodePath, Transform3D, Vector3};
+use crate::classes::Node3D;
+#[cfg(feature = "codegen-full")]
+use crate::classes::{MeshInstance3D, VisualInstance3D};
+use crate::meta::AsArg;
+use crate::obj::Gd;
+
+/// Manual extensions for the `Node3D` class.
+impl Node3D {
+    /// Returns a guard to modify the global transform, which is applied in a single [`set_global_transform()`][Self::set_global_transform]
+    /// call when the guard is dropped.
+    ///
+    /// Useful to combine several changes (translation, rotation, scale) without intermediate transform updates and notifications.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn f(mut node: Gd<Node3D>) {
+    /// let mut transform = node.global_transform_mut();
+    /// transform
+    ///     .translate_by(Vector3::new(0.0, 1.0, 0.0))
+    ///     .rotate_by(Vector3::UP, 0.5)
+    ///     .scale_by(Vector3::splat(2.0));
+    /// drop(transform); // Applied here.
+    /// # }
+    /// ```
+    pub fn global_transform_mut(&mut self) -> GlobalTransformMut<'_> {
+        let transform = self.get_global_transform();
+
+        GlobalTransformMut {
+            node: self,
+            transform,
+        }
+    }
+
+    /// Returns the world-space bounding box of the [`MeshInstance3D`] at `path`, relative to this node.
+    ///
+    /// Returns `None` if there is no node at `path`, or if it is not a `MeshInstance3D`. See
+    /// [`VisualInstance3D::world_aabb()`] for details.
+    #[cfg(feature = "codegen-full")]
+    pub fn global_aabb(&self, path: impl AsArg<NodePath>) -> Option<Aabb> {
+        self.try_get_node_as::<MeshInstance3D>(path)
+            .map(|mesh_instance| mesh_instance.world_aabb())
+    }
+}
+
+/// Guard that accumulates changes to a [`Node3D`]'s global transform and applies them on drop.
+///
+/// Returned by [`Node3D::global_transform_mut()`]. The guard holds a copy of the transform, which can also be accessed directly through
+/// `Deref`/`DerefMut`. All operations are relative to the global (parent-independent) coordinate system, like Godot's
+/// `global_translate()`, `global_rotate()` and `global_scale()`.
+pub struct GlobalTransformMut<'a> {
+    node: &'a mut Node3D,
+    transform: Transform3D,
+}
+
+impl GlobalTransformMut<'_> {
+    /// Moves the node by `offset` in global space.
+    pub fn translate_by(&mut self, offset: Vector3) -> &mut Self {
+        self.transform.origin += offset;
+        self
+    }
+
+    /// Rotates the node around the global `axis` by `angle` (in radians), keeping its position. The axis must be normalized.
+    pub fn rotate_by(&mut self, axis: Vector3, angle: real) -> &mut Self {
+        self.transform.basis = Basis::from_axis_angle(axis, angle) * self.transform.basis;
+        self
+    }
+
+    /// Scales the node along the global axes, keeping its position.
+    pub fn scale_by(&mut self, scale: Vector3) -> &mut Self {
+        self.transform.basis = self.transform.basis.scaled(scale);
+        self
+    }
+}
+
+impl std::ops::Deref for GlobalTransformMut<'_> {
+    type Target = Transform3D;
+
+    fn deref(&self) -> &Transform3D {
+        &self.transform
+    }
+}
+
+impl std::ops::DerefMut for GlobalTransformMut<'_> {
+    fn deref_mut(&mut self) -> &mut Transform3D {
+        &mut self.transform
+    }
+}
+
+impl Drop for GlobalTransformMut<'_> {
+    fn drop(&mut self) {
+        self.node.set_global_transform(self.transform);
+    }
+}
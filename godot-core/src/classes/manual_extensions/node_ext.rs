@@ -0,0 +1,382 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::VecDeque;
+
+use crate::builtin::{GString, NodePath};
+use crate::classes::notify::NodeNotification;
+use crate::classes::{Node, PackedScene};
+use crate::global::Error as GodotError;
+use crate::meta::error::IoError;
+use crate::meta::{arg_into_ref, AsArg, ToGodot};
+use crate::obj::{Gd, Inherits, NewAlloc, NewGd};
+
+/// Manual extensions for the `Node` class.
+impl Node {
+    /// ⚠️ Retrieves the node at path `path`, panicking if not found or bad type.
+    ///
+    /// # Panics
+    /// If the node is not found, or if it does not have type `T` or inherited.
+    pub fn get_node_as<T>(&self, path: impl AsArg<NodePath>) -> Gd<T>
+    where
+        T: Inherits<Node>,
+    {
+        arg_into_ref!(path);
+
+        self.try_get_node_as(path).unwrap_or_else(|| {
+            panic!(
+                "There is no node of type {ty} at path `{path}`",
+                ty = T::class_name()
+            )
+        })
+    }
+
+    /// Retrieves the node at path `path` (fallible).
+    ///
+    /// If the node is not found, or if it does not have type `T` or inherited,
+    /// `None` will be returned.
+    pub fn try_get_node_as<T>(&self, path: impl AsArg<NodePath>) -> Option<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        arg_into_ref!(path);
+
+        // TODO differentiate errors (not found, bad type) with Result
+        self.get_node_or_null(path)
+            .and_then(|node| node.try_cast::<T>().ok())
+    }
+
+    /// Returns an iterator over the direct children of this node, in tree order.
+    ///
+    /// The children are collected when the iterator is created; internal children are not included. Adding, removing or reordering
+    /// children during iteration does not affect the iterator, so it is safe to e.g. remove each child as it is visited.
+    pub fn children(&self) -> impl Iterator<Item = Gd<Node>> {
+        let children = self.get_children();
+        (0..children.len()).map(move |i| children.at(i))
+    }
+
+    /// Returns an iterator over the direct children of this node that are of type `T` or inherited.
+    ///
+    /// Children of other types are skipped. See [`children()`][Self::children] for details.
+    pub fn children_of<T>(&self) -> impl Iterator<Item = Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        self.children()
+            .filter_map(|child| child.try_cast::<T>().ok())
+    }
+
+    /// Returns an iterator over all descendants of this node, in depth-first pre-order.
+    ///
+    /// This node itself is not included. Each node's children are fetched when the node is visited.
+    pub fn descendants(&self) -> impl Iterator<Item = Gd<Node>> {
+        let mut stack: Vec<Gd<Node>> = self.children().collect();
+        stack.reverse();
+
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+
+            let first_child_index = stack.len();
+            stack.extend(node.children());
+            stack[first_child_index..].reverse();
+
+            Some(node)
+        })
+    }
+
+    /// Returns an iterator over this node and all its descendants, in breadth-first order.
+    ///
+    /// This node comes first, followed by its children, then its grandchildren, and so on. Each node's children are fetched
+    /// when the node is visited. Nodes that are freed before being visited are skipped.
+    pub fn traverse_bfs(&self) -> impl Iterator<Item = Gd<Node>> {
+        let mut queue = VecDeque::from([self.to_node_gd()]);
+
+        std::iter::from_fn(move || loop {
+            let node = queue.pop_front()?;
+            if !node.is_instance_valid() {
+                continue;
+            }
+
+            queue.extend(node.children());
+            return Some(node);
+        })
+    }
+
+    /// Returns an iterator over this node and all its descendants, in depth-first pre-order.
+    ///
+    /// Like [`descendants()`][Self::descendants], but includes this node. Nodes that are freed before being visited are skipped.
+    pub fn traverse_dfs(&self) -> impl Iterator<Item = Gd<Node>> {
+        let mut stack = vec![self.to_node_gd()];
+
+        std::iter::from_fn(move || loop {
+            let node = stack.pop()?;
+            if !node.is_instance_valid() {
+                continue;
+            }
+
+            let first_child_index = stack.len();
+            stack.extend(node.children());
+            stack[first_child_index..].reverse();
+
+            return Some(node);
+        })
+    }
+
+    /// Returns an iterator over the ancestors of this node, starting with its parent and ending with the root.
+    ///
+    /// This node itself is not included.
+    pub fn ancestors(&self) -> impl Iterator<Item = Gd<Node>> {
+        std::iter::successors(self.get_parent(), |node| node.get_parent())
+    }
+
+    /// Returns the path of this node, also for nodes that are not (yet) part of a scene tree.
+    ///
+    /// If the node is inside the tree, this is the absolute path returned by [`get_path()`][Self::get_path], e.g. `/root/Level/Player`.
+    /// Otherwise, `get_path()` would fail, so the path is built from the names of the node's ancestors instead. It then starts at the
+    /// topmost ancestor and is not absolute, e.g. `Level/Player` -- which becomes `/root/Level/Player` once `Level` is added to the root.
+    pub fn path_from_root(&self) -> NodePath {
+        if self.is_inside_tree() {
+            return self.get_path();
+        }
+
+        let mut names: Vec<String> = self
+            .ancestors()
+            .map(|node| node.get_name().to_string())
+            .collect();
+        names.reverse();
+        names.push(self.get_name().to_string());
+
+        NodePath::from(names.join("/"))
+    }
+
+    /// Adds `node` as a child of this node at the end of the current frame, using [`call_deferred()`][crate::classes::Object::call_deferred].
+    ///
+    /// Useful in contexts where [`add_child()`][Self::add_child] is not allowed, e.g. while the parent is still setting up its children
+    /// (`_ready()`, notifications) or when called from a signal emitted by a physics callback.
+    pub fn add_child_deferred<T>(&mut self, node: &Gd<T>)
+    where
+        T: Inherits<Node>,
+    {
+        self.call_deferred("add_child", &[node.to_variant()]);
+    }
+
+    /// Returns the direct child named `name`, creating and adding a new `C` with that name if there is none.
+    ///
+    /// Useful for "manager" children that should exist exactly once. The new child is added immediately with
+    /// [`add_child()`][Self::add_child], so subsequent calls find it, whether or not this node is inside the tree.
+    ///
+    /// While this node is busy setting up its children (e.g. during their `ready()` callbacks), Godot does not allow adding children.
+    /// In such cases, create the child manually and use [`add_child_deferred()`][Self::add_child_deferred].
+    ///
+    /// # Panics
+    /// - If `name` is empty or contains characters that are not allowed in node names (`.` `:` `@` `/` `"` `%`), since it would then
+    ///   be interpreted as a path or renamed by Godot.
+    /// - If a child named `name` exists, but does not have type `C` or inherited.
+    pub fn get_or_create_child<C>(&mut self, name: &str) -> Gd<C>
+    where
+        C: Inherits<Node> + NewAlloc,
+    {
+        const INVALID_CHARS: [char; 6] = ['.', ':', '@', '/', '"', '%'];
+        assert!(
+            !name.is_empty() && !name.contains(INVALID_CHARS),
+            "get_or_create_child(): `{name}` is not a valid node name"
+        );
+
+        if let Some(existing) = self.get_node_or_null(&NodePath::from(name)) {
+            return existing.try_cast::<C>().unwrap_or_else(|node| {
+                panic!(
+                    "child `{name}` has type {actual}, expected {expected}",
+                    actual = node.get_class(),
+                    expected = C::class_name()
+                )
+            });
+        }
+
+        let child = C::new_alloc();
+        child.clone().upcast::<Node>().set_name(name);
+        self.add_child(&child);
+
+        child
+    }
+
+    /// Calls [`queue_free()`][Self::queue_free] on each child of this node.
+    ///
+    /// The children are deleted at the end of the current frame; until then, they remain in the tree.
+    pub fn queue_free_children(&mut self) {
+        for mut child in self.children() {
+            child.queue_free();
+        }
+    }
+
+    /// Removes all children from this node, without freeing them.
+    ///
+    /// The removed nodes are orphans afterward; you are responsible for freeing them or adding them to another parent.
+    pub fn remove_all_children(&mut self) {
+        let children: Vec<Gd<Node>> = self.children().collect();
+        for child in children {
+            self.remove_child(&child);
+        }
+    }
+
+    /// Moves this node to the end of its parent's children, so it is processed last and drawn on top of its siblings in 2D.
+    ///
+    /// Does nothing if the node has no parent. Internal children are moved to the end of their internal group instead.
+    pub fn move_to_front(&mut self) {
+        if let Some(mut parent) = self.get_parent() {
+            // Godot resolves negative indices within the child's own group (internal front, regular, internal back), so no child count is
+            // needed -- which would have to match that group and could be zero.
+            parent.move_child(&self.to_node_gd(), -1);
+        }
+    }
+
+    /// Moves this node to the start of its parent's children, so it is processed first and drawn behind its siblings in 2D.
+    ///
+    /// Does nothing if the node has no parent.
+    pub fn move_to_back(&mut self) {
+        self.move_to_sibling_index(0);
+    }
+
+    /// Moves this node to position `index` among its siblings. Siblings in between are shifted by one.
+    ///
+    /// Does nothing if the node has no parent. See also [`move_child()`][Self::move_child].
+    ///
+    /// # Panics
+    /// If `index` is not smaller than the number of children of the parent.
+    pub fn move_to_sibling_index(&mut self, index: usize) {
+        let Some(mut parent) = self.get_parent() else {
+            return;
+        };
+
+        let child_count = parent.get_child_count() as usize;
+        assert!(
+            index < child_count,
+            "move_to_sibling_index(): index {index} out of bounds for {child_count} children"
+        );
+
+        parent.move_child(&self.to_node_gd(), index as i32);
+    }
+
+    /// Swaps the positions of this node and `other` among their parent's children.
+    ///
+    /// Does nothing if `other` is this node.
+    ///
+    /// # Panics
+    /// If the two nodes do not have the same parent.
+    pub fn swap_with_sibling(&mut self, other: &Gd<Node>) {
+        let this = self.to_node_gd();
+        if &this == other {
+            return;
+        }
+
+        let mut parent = match (self.get_parent(), other.get_parent()) {
+            (Some(parent), Some(other_parent)) if parent == other_parent => parent,
+            _ => panic!("swap_with_sibling(): nodes must have the same parent"),
+        };
+
+        // Moving the front node behind the back node shifts the latter one position forward, right where it needs to go next.
+        let (front, back) = if self.get_index() < other.get_index() {
+            (this, other.clone())
+        } else {
+            (other.clone(), this)
+        };
+        let front_index = front.get_index();
+        let back_index = back.get_index();
+
+        parent.move_child(&front, back_index);
+        parent.move_child(&back, front_index);
+    }
+
+    /// Packs this node and its owned descendants into a new [`PackedScene`].
+    ///
+    /// Only descendants whose [`owner`][Node::get_owner] is this node are included, just like in
+    /// [`PackedScene::pack()`][PackedScene::pack].
+    ///
+    /// Returns an error if Godot fails to pack the node.
+    pub fn to_packed_scene(&self) -> Result<Gd<PackedScene>, IoError> {
+        let mut scene = PackedScene::new_gd();
+        let result = scene.pack(&self.to_node_gd());
+
+        if result == GodotError::OK {
+            Ok(scene)
+        } else {
+            Err(IoError::packing(result, self.get_name().to_string()))
+        }
+    }
+
+    /// Packs this node into a [`PackedScene`] and saves it at `path`.
+    ///
+    /// Combines [`to_packed_scene()`][Self::to_packed_scene] and [`try_save()`][crate::tools::try_save]; see their docs for details.
+    pub fn save_as_scene(&self, path: impl AsArg<GString>) -> Result<(), IoError> {
+        arg_into_ref!(path);
+
+        let scene = self.to_packed_scene()?;
+        crate::tools::try_save(&scene, path)
+    }
+
+    /// Returns a textual representation of this node's subtree, one node per line.
+    ///
+    /// Each line shows the node's name, class and instance ID. Unlike [`print_tree_pretty()`][Self::print_tree_pretty], the result is
+    /// returned instead of printed, which makes it useful for logging and test assertions. Internal children are not included.
+    ///
+    /// Example output:
+    /// ```text
+    /// Level (Node3D#1234)
+    /// ┠╴Player (CharacterBody3D#1235)
+    /// ┃  ┖╴Camera (Camera3D#1236)
+    /// ┖╴Sun (DirectionalLight3D#1237)
+    /// ```
+    pub fn tree_to_string(&self) -> String {
+        let mut lines = Vec::new();
+        collect_tree_lines(self, &mut lines, "", "");
+
+        lines.join("\n")
+    }
+
+    /// ⚠️ Sends a notification to this node and all its descendants, with a typed notification code.
+    ///
+    /// Typed counterpart to [`propagate_notification()`][Self::propagate_notification]. Like [`notify()`][Self::notify], the same panics
+    /// apply when a user-defined node is currently bound.
+    #[doc(alias = "propagate_typed")]
+    pub fn propagate_notify(&mut self, what: NodeNotification) {
+        self.propagate_notification(i32::from(what));
+    }
+
+    /// Returns a `Gd` pointer to this node.
+    fn to_node_gd(&self) -> Gd<Node> {
+        // SAFETY: `self` is a live engine object, since it is only reachable through a `Gd<T>`.
+        unsafe { Gd::from_obj_sys(self.__object_ptr()) }
+    }
+}
+
+fn collect_tree_lines(node: &Node, lines: &mut Vec<String>, line_prefix: &str, child_prefix: &str) {
+    lines.push(format!(
+        "{line_prefix}{name} ({class}#{id})",
+        name = node.get_name(),
+        class = node.get_class(),
+        id = node.get_instance_id(),
+    ));
+
+    let child_count = node.get_child_count();
+    for i in 0..child_count {
+        let child = node
+            .get_child(i)
+            .expect("child index within get_child_count()");
+
+        let (branch, indent) = if i + 1 == child_count {
+            ("┖╴", "   ")
+        } else {
+            ("┠╴", "┃  ")
+        };
+
+        collect_tree_lines(
+            &child,
+            lines,
+            &format!("{child_prefix}{branch}"),
+            &format!("{child_prefix}{indent}"),
+        );
+    }
+}
@@ -0,0 +1,134 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use godot_ffi as sys;
+
+use crate::builtin::{Dictionary, GString, StringName, Variant, VariantType};
+use crate::classes::{Object, Resource};
+use crate::global::PropertyUsageFlags;
+use crate::meta::{AsArg, ToGodot};
+use crate::obj::{EngineBitfield, Gd, InstanceId};
+use crate::tools::try_load;
+
+/// Manual extensions for the `Object` class.
+impl Object {
+    /// Returns a snapshot of all stored properties, mapping property names to values.
+    ///
+    /// Only properties with [`PropertyUsageFlags::STORAGE`] are included, i.e. those that Godot would save to a scene or resource file.
+    /// Object references in object-typed properties are not stored directly: resources saved to a file are represented by their
+    /// [`resource_path`][Resource::get_path], and all other objects by their instance ID (as integer). Untyped (`Variant`) properties are
+    /// stored as-is, even if they hold an object, since their type could not be recovered on restore.
+    ///
+    /// Use [`from_variant_dict()`][Self::from_variant_dict] to restore the state.
+    pub fn to_variant_dict(&self) -> Dictionary {
+        let mut dict = Dictionary::new();
+
+        for property in self.get_property_list().iter_shared() {
+            let usage = PropertyUsageFlags::from_ord(property.at("usage").to::<u64>());
+            if !usage.is_set(PropertyUsageFlags::STORAGE) {
+                continue;
+            }
+
+            let name = property.at("name").to::<GString>();
+            let value = self.get(&StringName::from(&name));
+
+            // Must match the check in from_variant_dict(), so that the conversion is reversed.
+            let value = if property_variant_type(&property) == VariantType::OBJECT {
+                snapshot_object(value)
+            } else {
+                value
+            };
+
+            dict.set(name, value);
+        }
+
+        dict
+    }
+
+    /// Restores properties from a snapshot created by [`to_variant_dict()`][Self::to_variant_dict].
+    ///
+    /// Keys that do not name a property of this object are ignored. For object-typed properties, resource paths are loaded again and
+    /// instance IDs are resolved to the live object; objects that have been freed in the meantime are restored as `null`.
+    pub fn from_variant_dict(&mut self, dict: &Dictionary) {
+        let property_types: HashMap<StringName, VariantType> = self
+            .get_property_list()
+            .iter_shared()
+            .map(|property| {
+                let name = property.at("name").to::<StringName>();
+                (name, property_variant_type(&property))
+            })
+            .collect();
+
+        for (key, value) in dict.iter_shared() {
+            let Ok(name) = key.try_to::<StringName>() else {
+                continue;
+            };
+            let Some(&property_type) = property_types.get(&name) else {
+                continue;
+            };
+
+            let value = if property_type == VariantType::OBJECT {
+                restore_object(value)
+            } else {
+                value
+            };
+
+            self.set(&name, &value);
+        }
+    }
+
+    /// Sets a property at idle time, i.e. at the end of the current frame, converting `value` to a variant.
+    ///
+    /// Typed counterpart to [`set_deferred()`][Self::set_deferred]. Useful to modify physics state from within physics callbacks.
+    /// If the object is freed before the end of the frame, the assignment is dropped.
+    pub fn set_property_deferred(&mut self, property: impl AsArg<StringName>, value: impl ToGodot) {
+        self.set_deferred(property, &value.to_variant());
+    }
+
+    /// Returns the Rust type name of this object's class, if it is a class registered by this extension.
+    ///
+    /// Returns `None` for engine classes and classes defined elsewhere. See
+    /// [`class_name_to_rust_name()`][crate::registry::class::class_name_to_rust_name] for details on the format.
+    pub fn get_class_rust_type(&self) -> Option<&'static str> {
+        let class_name = StringName::from(&self.get_class());
+        crate::registry::class::class_name_to_rust_name(&class_name)
+    }
+}
+
+/// Declared type of a property, given its entry in `get_property_list()`. `NIL` for untyped (`Variant`) properties.
+fn property_variant_type(property: &Dictionary) -> VariantType {
+    let sys_type = property.at("type").to::<i64>() as sys::GDExtensionVariantType;
+    VariantType::from_sys(sys_type)
+}
+
+/// Replaces an object reference by its resource path or instance ID.
+fn snapshot_object(value: Variant) -> Variant {
+    let Ok(Some(object)) = value.try_to::<Option<Gd<Object>>>() else {
+        return value;
+    };
+
+    match object.try_cast::<Resource>() {
+        Ok(resource) if !resource.get_path().is_empty() => resource.get_path().to_variant(),
+        Ok(resource) => resource.instance_id().to_variant(),
+        Err(object) => object.instance_id().to_variant(),
+    }
+}
+
+/// Inverse of [`snapshot_object()`].
+fn restore_object(value: Variant) -> Variant {
+    match value.get_type() {
+        VariantType::STRING => try_load::<Resource>(&value.to::<GString>())
+            .ok()
+            .to_variant(),
+        VariantType::INT => InstanceId::try_from_i64(value.to())
+            .and_then(|id| Gd::<Object>::try_from_instance_id(id).ok())
+            .to_variant(),
+        _ => value,
+    }
+}
@@ -0,0 +1,39 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Array, Rid, Vector3};
+use crate::classes::{PhysicsRayQueryParameters3D, RayCast3D, World3D};
+use crate::obj::Gd;
+use crate::tools::RaycastHit;
+
+/// Manual extensions for the `RayCast3D` class.
+impl RayCast3D {
+    /// Casts a ray from `from` to `to` in `world` immediately, returning the first hit.
+    ///
+    /// Unlike a `RayCast3D` node, this needs no node in the scene tree and no [`force_raycast_update()`][Self::force_raycast_update].
+    /// It queries the world's [`PhysicsDirectSpaceState3D`][crate::classes::PhysicsDirectSpaceState3D] directly, considering only
+    /// collision layers in `collision_mask` and ignoring objects whose RIDs are in `exclude`.
+    ///
+    /// Returns `None` if nothing was hit. The space state may only be accessed from the main thread, during or after physics processing;
+    /// see Godot's [ray-casting tutorial](https://docs.godotengine.org/en/stable/tutorials/physics/ray-casting.html).
+    pub fn immediate_cast(
+        from: Vector3,
+        to: Vector3,
+        collision_mask: u32,
+        world: &Gd<World3D>,
+        exclude: &[Rid],
+    ) -> Option<RaycastHit> {
+        let mut space_state = world.get_direct_space_state()?;
+
+        let mut query = PhysicsRayQueryParameters3D::create(from, to)?;
+        query.set_collision_mask(collision_mask);
+        query.set_exclude(&exclude.iter().copied().collect::<Array<Rid>>());
+
+        let result = space_state.intersect_ray(&query);
+        RaycastHit::from_dictionary(&result)
+    }
+}
@@ -0,0 +1,121 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::Vector3;
+use crate::classes::RigidBody3D;
+
+/// Manual extensions for the `RigidBody3D` class.
+impl RigidBody3D {
+    /// Returns a builder that accumulates forces, torques and impulses, and applies them together in [`apply()`][PhysicsImpulseBuilder::apply].
+    ///
+    /// Central forces, torques and their impulse counterparts are summed up and passed to Godot in one call each, so the order in which they
+    /// were added does not matter. Typically used inside `physics_process()`.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use godot::classes::RigidBody3D;
+    /// # fn f(mut body: Gd<RigidBody3D>) {
+    /// body.physics_impulses()
+    ///     .central_force(Vector3::new(0.0, 9.8, 0.0))
+    ///     .torque(Vector3::new(0.0, 1.0, 0.0))
+    ///     .impulse_at_position(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.5, 0.0))
+    ///     .apply();
+    /// # }
+    /// ```
+    pub fn physics_impulses(&mut self) -> PhysicsImpulseBuilder<'_> {
+        PhysicsImpulseBuilder {
+            body: self,
+            central_force: Vector3::ZERO,
+            central_impulse: Vector3::ZERO,
+            torque: Vector3::ZERO,
+            torque_impulse: Vector3::ZERO,
+            forces_at_position: Vec::new(),
+            impulses_at_position: Vec::new(),
+        }
+    }
+}
+
+/// Builder that collects forces and impulses for a [`RigidBody3D`], applied in one go by [`apply()`][Self::apply].
+///
+/// Returned by [`RigidBody3D::physics_impulses()`]. Forces are continuous and act during the next physics step, while impulses change
+/// the velocity immediately; see Godot's `apply_force()` and `apply_impulse()` docs. All positions are relative to the body's origin,
+/// in global coordinates.
+#[must_use = "forces and impulses are only applied when calling apply()"]
+pub struct PhysicsImpulseBuilder<'a> {
+    body: &'a mut RigidBody3D,
+    central_force: Vector3,
+    central_impulse: Vector3,
+    torque: Vector3,
+    torque_impulse: Vector3,
+    forces_at_position: Vec<(Vector3, Vector3)>,
+    impulses_at_position: Vec<(Vector3, Vector3)>,
+}
+
+impl PhysicsImpulseBuilder<'_> {
+    /// Adds a force at the center of mass, without rotational effect.
+    pub fn central_force(mut self, force: Vector3) -> Self {
+        self.central_force += force;
+        self
+    }
+
+    /// Adds an impulse at the center of mass, without rotational effect.
+    pub fn central_impulse(mut self, impulse: Vector3) -> Self {
+        self.central_impulse += impulse;
+        self
+    }
+
+    /// Adds a rotational force.
+    pub fn torque(mut self, torque: Vector3) -> Self {
+        self.torque += torque;
+        self
+    }
+
+    /// Adds a rotational impulse.
+    pub fn torque_impulse(mut self, impulse: Vector3) -> Self {
+        self.torque_impulse += impulse;
+        self
+    }
+
+    /// Adds a force at `position`, which may cause rotation.
+    pub fn force_at_position(mut self, force: Vector3, position: Vector3) -> Self {
+        self.forces_at_position.push((force, position));
+        self
+    }
+
+    /// Adds an impulse at `position`, which may cause rotation.
+    pub fn impulse_at_position(mut self, impulse: Vector3, position: Vector3) -> Self {
+        self.impulses_at_position.push((impulse, position));
+        self
+    }
+
+    /// Applies all accumulated forces and impulses to the body.
+    ///
+    /// Zero-valued central forces and torques are skipped, so they do not wake up a sleeping body.
+    pub fn apply(self) {
+        let body = self.body;
+
+        if self.central_force != Vector3::ZERO {
+            body.apply_central_force(self.central_force);
+        }
+        if self.central_impulse != Vector3::ZERO {
+            body.apply_central_impulse(self.central_impulse);
+        }
+        if self.torque != Vector3::ZERO {
+            body.apply_torque(self.torque);
+        }
+        if self.torque_impulse != Vector3::ZERO {
+            body.apply_torque_impulse(self.torque_impulse);
+        }
+
+        for (force, position) in self.forces_at_position {
+            body.apply_force_ex(force).position(position).done();
+        }
+        for (impulse, position) in self.impulses_at_position {
+            body.apply_impulse_ex(impulse).position(position).done();
+        }
+    }
+}
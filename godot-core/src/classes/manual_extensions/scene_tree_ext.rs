@@ -0,0 +1,149 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{GString, StringName};
+use crate::classes::notify::NodeNotification;
+use crate::classes::{Node, SceneTree};
+use crate::meta::AsArg;
+use crate::obj::Gd;
+
+/// Manual extensions for the `SceneTree` class.
+impl SceneTree {
+    /// ⚠️ Sends a notification to all nodes in `group`, with a typed notification code.
+    ///
+    /// Typed counterpart to [`notify_group()`][Self::notify_group]. Unlike [`Node::propagate_notify()`], descendants of the group members
+    /// are not notified.
+    pub fn call_group_notification(
+        &mut self,
+        group: impl AsArg<StringName>,
+        what: NodeNotification,
+    ) {
+        self.notify_group(group, i32::from(what));
+    }
+
+    /// Returns a future that resolves on the next `process_frame` signal, i.e. one frame later.
+    ///
+    /// Equivalent to GDScript's `await get_tree().process_frame`. See [`Signal::await_once()`][crate::builtin::Signal::await_once] for details.
+    #[cfg(since_api = "4.2")]
+    pub fn next_frame(&self) -> impl std::future::Future<Output = ()> {
+        crate::builtin::Signal::from_object_signal(&self.to_tree_gd(), "process_frame").await_once()
+    }
+
+    /// Returns a future that resolves on the next `physics_frame` signal, i.e. one physics tick later.
+    ///
+    /// Equivalent to GDScript's `await get_tree().physics_frame`. See [`Signal::await_once()`][crate::builtin::Signal::await_once] for details.
+    #[cfg(since_api = "4.2")]
+    pub fn next_physics_frame(&self) -> impl std::future::Future<Output = ()> {
+        crate::builtin::Signal::from_object_signal(&self.to_tree_gd(), "physics_frame").await_once()
+    }
+
+    /// Returns a future that resolves on the next emission of a scene-tree signal, optionally filtered by node name.
+    ///
+    /// For the node signals, `name_pattern` is matched against the node's name using [`match_glob()`][crate::builtin::StringName::match_glob]
+    /// (`*` and `?` wildcards); emissions for other nodes are ignored. The future resolves with the corresponding [`SceneTreeEvent`].
+    ///
+    /// Nodes in events may have been freed by the time the future is polled, e.g. after `NodeRemoved`. See
+    /// [`Signal::await_matching()`][crate::builtin::Signal::await_matching] for details on execution.
+    #[cfg(since_api = "4.2")]
+    pub fn await_signal(
+        &self,
+        signal: SceneTreeSignal,
+    ) -> impl std::future::Future<Output = SceneTreeEvent> {
+        let pattern = signal.name_pattern().cloned();
+        let future = crate::builtin::Signal::from_object_signal(&self.to_tree_gd(), signal.name())
+            .await_matching(move |args| {
+                let Some(pattern) = &pattern else {
+                    return true;
+                };
+
+                args.first()
+                    .and_then(|arg| arg.try_to::<Gd<Node>>().ok())
+                    .is_some_and(|node| node.get_name().match_glob(pattern))
+            });
+
+        async move {
+            let args = future.await;
+            let node = || {
+                args.first()
+                    .expect("scene tree signal has node parameter")
+                    .to::<Gd<Node>>()
+            };
+
+            match signal {
+                SceneTreeSignal::NodeAdded { .. } => SceneTreeEvent::NodeAdded(node()),
+                SceneTreeSignal::NodeRemoved { .. } => SceneTreeEvent::NodeRemoved(node()),
+                SceneTreeSignal::NodeRenamed { .. } => SceneTreeEvent::NodeRenamed(node()),
+                SceneTreeSignal::TreeChanged => SceneTreeEvent::TreeChanged,
+                SceneTreeSignal::ProcessFrame => SceneTreeEvent::ProcessFrame,
+                SceneTreeSignal::PhysicsFrame => SceneTreeEvent::PhysicsFrame,
+            }
+        }
+    }
+
+    /// Returns a `Gd` pointer to this scene tree.
+    #[cfg(since_api = "4.2")]
+    fn to_tree_gd(&self) -> Gd<SceneTree> {
+        // SAFETY: `self` is a live engine object, since it is only reachable through a `Gd<T>`.
+        unsafe { Gd::from_obj_sys(self.__object_ptr()) }
+    }
+}
+
+/// Scene-tree signal to wait for, used by [`SceneTree::await_signal()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum SceneTreeSignal {
+    /// `node_added`: a node entered the tree. Optionally only for node names matching a glob pattern.
+    NodeAdded { name_pattern: Option<GString> },
+
+    /// `node_removed`: a node exited the tree. Optionally only for node names matching a glob pattern.
+    NodeRemoved { name_pattern: Option<GString> },
+
+    /// `node_renamed`: a node in the tree was renamed. Optionally only for (new) node names matching a glob pattern.
+    NodeRenamed { name_pattern: Option<GString> },
+
+    /// `tree_changed`: the tree's hierarchy changed.
+    TreeChanged,
+
+    /// `process_frame`: right before `process()` is called on nodes.
+    ProcessFrame,
+
+    /// `physics_frame`: right before `physics_process()` is called on nodes.
+    PhysicsFrame,
+}
+
+impl SceneTreeSignal {
+    /// Name of the signal on `SceneTree`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::NodeAdded { .. } => "node_added",
+            Self::NodeRemoved { .. } => "node_removed",
+            Self::NodeRenamed { .. } => "node_renamed",
+            Self::TreeChanged => "tree_changed",
+            Self::ProcessFrame => "process_frame",
+            Self::PhysicsFrame => "physics_frame",
+        }
+    }
+
+    fn name_pattern(&self) -> Option<&GString> {
+        match self {
+            Self::NodeAdded { name_pattern }
+            | Self::NodeRemoved { name_pattern }
+            | Self::NodeRenamed { name_pattern } => name_pattern.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Scene-tree signal emission, returned by [`SceneTree::await_signal()`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum SceneTreeEvent {
+    NodeAdded(Gd<Node>),
+    NodeRemoved(Gd<Node>),
+    NodeRenamed(Gd<Node>),
+    TreeChanged,
+    ProcessFrame,
+    PhysicsFrame,
+}
@@ -0,0 +1,38 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::classes::{Engine, SceneTree, SceneTreeTimer};
+use crate::obj::Gd;
+
+/// Manual extensions for the `SceneTreeTimer` class.
+///
+/// Named constructors for the common cases of [`SceneTree::create_timer()`]. For full control over all flags, use
+/// [`SceneTree::create_timer_ex()`], e.g. `tree.create_timer_ex(1.5).ignore_time_scale(true).done()`.
+impl SceneTreeTimer {
+    /// Starts a one-shot timer on `tree` that counts down during process (idle) frames.
+    ///
+    /// The timer keeps running while the tree is paused and respects [`Engine::time_scale()`]. Await its `timeout` signal, e.g. with
+    /// [`Signal::await_once()`][crate::builtin::Signal::await_once].
+    pub fn idle(tree: &Gd<SceneTree>, seconds: f64) -> Gd<SceneTreeTimer> {
+        Self::start(tree, seconds, false)
+    }
+
+    /// Starts a one-shot timer on `tree` that counts down during physics frames.
+    ///
+    /// Same as [`idle()`][Self::idle], but the timer is updated at the end of each physics frame instead of each process frame.
+    pub fn physics(tree: &Gd<SceneTree>, seconds: f64) -> Gd<SceneTreeTimer> {
+        Self::start(tree, seconds, true)
+    }
+
+    fn start(tree: &Gd<SceneTree>, seconds: f64, in_physics: bool) -> Gd<SceneTreeTimer> {
+        tree.clone()
+            .create_timer_ex(seconds)
+            .process_in_physics(in_physics)
+            .done()
+            .expect("SceneTree::create_timer() returns a timer")
+    }
+}
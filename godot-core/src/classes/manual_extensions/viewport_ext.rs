@@ -0,0 +1,35 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Plane, Vector3};
+use crate::classes::{Camera3D, Viewport};
+use crate::obj::Gd;
+use crate::tools::Ray3D;
+
+/// Manual extensions for the `Viewport` class.
+impl Viewport {
+    /// Returns the ray from `camera` through the current mouse position, in global coordinates.
+    ///
+    /// Combines [`get_mouse_position()`][Self::get_mouse_position] with [`Camera3D::project_ray_origin()`] and
+    /// [`Camera3D::project_ray_normal()`]. `camera` should be rendering to this viewport, otherwise the result is meaningless.
+    pub fn project_mouse_ray(&self, camera: &Gd<Camera3D>) -> Ray3D {
+        let mouse_position = self.get_mouse_position();
+
+        Ray3D::new(
+            camera.project_ray_origin(mouse_position),
+            camera.project_ray_normal(mouse_position),
+        )
+    }
+
+    /// Returns the point on `plane` below the mouse cursor, as seen from `camera`.
+    ///
+    /// Useful for placing objects on a ground plane without physics. Returns `None` if the mouse ray is parallel to the plane or
+    /// points away from it, e.g. when the cursor is above the horizon. See [`project_mouse_ray()`][Self::project_mouse_ray].
+    pub fn screen_to_world_plane(&self, camera: &Gd<Camera3D>, plane: Plane) -> Option<Vector3> {
+        self.project_mouse_ray(camera).intersect_plane(plane)
+    }
+}
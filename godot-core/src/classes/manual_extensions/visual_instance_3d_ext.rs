@@ -0,0 +1,21 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::Aabb;
+use crate::classes::{Node3D, VisualInstance3D};
+
+/// Manual extensions for the `VisualInstance3D` class.
+impl VisualInstance3D {
+    /// Returns the bounding box of this instance in global (world) space.
+    ///
+    /// [`get_aabb()`][Self::get_aabb] is relative to the node itself; this applies the node's
+    /// [global transform][Node3D::get_global_transform]. Since the box stays axis-aligned, rotations can make it larger than the
+    /// transformed geometry.
+    pub fn world_aabb(&self) -> Aabb {
+        self.get_global_transform() * self.get_aabb()
+    }
+}
@@ -21,6 +21,9 @@ mod manual_extensions;
 
 // Re-exports all generated classes, interface traits and sidecar modules.
 pub use crate::gen::classes::*;
+#[cfg(feature = "codegen-full")]
+pub use manual_extensions::PhysicsImpulseBuilder;
+pub use manual_extensions::{GlobalTransformMut, SceneTreeEvent, SceneTreeSignal};
 
 /// Support for Godot _native structures_.
 ///
@@ -41,10 +41,6 @@ pub use crate::emit_deprecated_warning;
 	More information on https://github.com/godot-rust/gdext/pull/844"]
 pub const fn init_default() {}
 
-#[deprecated = "\nThe attribute key #[class(editor_plugin)] is now implied by #[class(base = EditorPlugin)]. It is ignored.\n\
-	More information on https://github.com/godot-rust/gdext/pull/884"]
-pub const fn class_editor_plugin() {}
-
 #[deprecated = "\nThe attribute key #[class(hidden)] has been renamed to #[class(internal)], following Godot terminology.\n\
     More information on https://github.com/godot-rust/gdext/pull/884"]
 pub const fn class_hidden() {}
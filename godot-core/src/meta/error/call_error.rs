@@ -103,6 +103,23 @@ impl CallError {
         &self.function_name
     }
 
+    /// Iterates over all chained source errors, starting with the immediate [`source()`][Error::source].
+    ///
+    /// This repeatedly calls `source()` on each error, until one returns `None`. `self` is not included. Useful to format error trees,
+    /// for example when a dynamic call fails inside another dynamic call.
+    ///
+    /// ```no_run
+    /// # use godot::meta::error::CallError;
+    /// fn print_chain(err: &CallError) {
+    ///     for (depth, source) in err.source_chain().enumerate() {
+    ///         println!("{:indent$}caused by: {source}", "", indent = depth * 2);
+    ///     }
+    /// }
+    /// ```
+    pub fn source_chain(&self) -> impl Iterator<Item = &(dyn Error + 'static)> {
+        std::iter::successors(self.source(), |err| err.source())
+    }
+
     // ------------------------------------------------------------------------------------------------------------------------------------------
     // Constructors returning Result<(), Self>; possible failure
 
@@ -140,8 +157,10 @@ impl CallError {
         let explicit_args_str = join_args(explicit_args.iter().map(|arg| arg.to_variant()));
         let vararg_str = if varargs.is_empty() {
             String::new()
+        } else if explicit_args.is_empty() {
+            format!("[va] {}", join_typed_args(varargs))
         } else {
-            format!(", [va] {}", join_args(varargs.iter().cloned()))
+            format!(", [va] {}", join_typed_args(varargs))
         };
 
         let call_expr = format!("{call_ctx}({explicit_args_str}{vararg_str})");
@@ -215,6 +234,11 @@ impl CallError {
         )
     }
 
+    /// Returns an error for an engine method that reported a Godot [`Error`](crate::global::Error) code instead of succeeding.
+    pub(crate) fn failed_engine_error(call_ctx: &CallContext, error: crate::global::Error) -> Self {
+        Self::new(call_ctx, format!("engine returned {error:?}"), None)
+    }
+
     fn failed_param_count(
         call_ctx: &CallContext,
         arg_count: usize,
@@ -404,6 +428,15 @@ fn join_args(args: impl Iterator<Item = Variant>) -> String {
     join_debug(args)
 }
 
+/// Like [`join_args()`], but annotates each argument with its index and type, e.g. `#0(INT):42, #1(STRING):"foo"`.
+fn join_typed_args(args: &[Variant]) -> String {
+    args.iter()
+        .enumerate()
+        .map(|(i, arg)| format!("#{i}({:?}):{arg:?}", arg.get_type()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 fn plural(count: usize) -> &'static str {
     if count == 1 {
         ""
@@ -0,0 +1,76 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use godot_ffi::VariantType;
+
+use crate::builtin::Variant;
+use crate::meta::error::ConvertError;
+
+/// Error when reading a typed value from a [`Dictionary`][crate::builtin::Dictionary].
+///
+/// Returned by [`Dictionary::try_get_typed()`][crate::builtin::Dictionary::try_get_typed].
+#[derive(Debug)]
+pub enum DictError {
+    /// The dictionary has no entry for `key`.
+    KeyNotFound {
+        /// The key that was looked up.
+        key: Variant,
+    },
+
+    /// The entry for `key` exists, but its value cannot be converted to the requested type.
+    TypeMismatch {
+        /// The key that was looked up.
+        key: Variant,
+
+        /// Rust type that was requested.
+        expected: &'static str,
+
+        /// Godot type of the stored value.
+        actual: VariantType,
+
+        /// Conversion error, which also holds the stored value.
+        source: ConvertError,
+    },
+}
+
+impl DictError {
+    /// The key that was looked up.
+    pub fn key(&self) -> &Variant {
+        match self {
+            Self::KeyNotFound { key } | Self::TypeMismatch { key, .. } => key,
+        }
+    }
+}
+
+impl fmt::Display for DictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::KeyNotFound { key } => write!(f, "key {key:?} missing in dictionary"),
+            Self::TypeMismatch {
+                key,
+                expected,
+                actual,
+                source,
+            } => write!(
+                f,
+                "value for key {key:?} has type {actual:?}, expected {expected}: {source}"
+            ),
+        }
+    }
+}
+
+impl Error for DictError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::KeyNotFound { .. } => None,
+            Self::TypeMismatch { source, .. } => Some(source),
+        }
+    }
+}
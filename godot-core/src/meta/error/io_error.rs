@@ -23,6 +23,7 @@ impl fmt::Display for IoError {
         match &self.data {
             ErrorData::Load(err) => err.fmt(f),
             ErrorData::Save(err) => err.fmt(f),
+            ErrorData::Pack(err) => err.fmt(f),
             ErrorData::GFile(err) => err.fmt(f),
         }
     }
@@ -34,6 +35,7 @@ impl Error for IoError {
         match &self.data {
             ErrorData::Load(err) => Some(err),
             ErrorData::Save(err) => Some(err),
+            ErrorData::Pack(err) => Some(err),
             ErrorData::GFile(err) => Some(err),
         }
     }
@@ -50,6 +52,15 @@ impl IoError {
         }
     }
 
+    pub(crate) fn packing(error: GodotError, node_name: String) -> Self {
+        Self {
+            data: ErrorData::Pack(PackerError {
+                node_name,
+                godot_error: error,
+            }),
+        }
+    }
+
     pub(crate) fn loading(class: String, path: String) -> Self {
         Self {
             data: ErrorData::Load(LoaderError {
@@ -102,6 +113,7 @@ impl IoError {
 enum ErrorData {
     Load(LoaderError),
     Save(SaverError),
+    Pack(PackerError),
     GFile(GFileError),
 }
 
@@ -163,6 +175,28 @@ impl fmt::Display for SaverError {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+#[derive(Debug)]
+struct PackerError {
+    node_name: String,
+    godot_error: GodotError,
+}
+
+impl Error for PackerError {}
+
+impl fmt::Display for PackerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let node_name = &self.node_name;
+        let godot_error = &self.godot_error;
+
+        write!(
+            f,
+            "can't pack node '{node_name}' into a scene; Godot error: {godot_error:?}"
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 #[derive(Debug)]
 struct GFileError {
     kind: GFileErrorKind,
@@ -9,8 +9,10 @@
 
 mod call_error;
 mod convert_error;
+mod dict_error;
 mod io_error;
 
 pub use call_error::*;
 pub use convert_error::*;
+pub use dict_error::*;
 pub use io_error::*;
@@ -357,6 +357,29 @@ impl FromGodot for u64 {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Duration: represented as float seconds, like Godot's `Timer.wait_time` or tween durations.
+
+impl GodotConvert for std::time::Duration {
+    type Via = f64;
+}
+
+impl ToGodot for std::time::Duration {
+    type ToVia<'v> = f64;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        self.as_secs_f64()
+    }
+}
+
+impl FromGodot for std::time::Duration {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        // Fails for negative, non-finite and overflowing values.
+        std::time::Duration::try_from_secs_f64(via)
+            .map_err(|rust_err| ConvertError::with_error_value(rust_err, via))
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Collections
 
@@ -98,6 +98,18 @@ impl PropertyInfo {
         Self { hint_info, ..self }
     }
 
+    /// Change the `hint` and `hint_string` to the given values.
+    ///
+    /// Shorthand for [`with_hint_info(PropertyHintInfo::new(hint, hint_string))`][Self::with_hint_info].
+    pub fn with_hint(self, hint: PropertyHint, hint_string: impl Into<GString>) -> Self {
+        self.with_hint_info(PropertyHintInfo::new(hint, hint_string))
+    }
+
+    /// Change the `usage` flags, e.g. to `PropertyUsageFlags::STORAGE` for a property that is saved but not shown in the editor.
+    pub fn with_usage(self, usage: PropertyUsageFlags) -> Self {
+        Self { usage, ..self }
+    }
+
     /// Create a new `PropertyInfo` representing a group in Godot.
     ///
     /// See [`EditorInspector`](https://docs.godotengine.org/en/latest/classes/class_editorinspector.html#class-editorinspector) in Godot for
@@ -206,6 +218,16 @@ pub struct PropertyHintInfo {
 }
 
 impl PropertyHintInfo {
+    /// Create a new `PropertyHintInfo` from a typed `hint` and its `hint_string`.
+    ///
+    /// The expected hint string format depends on the hint; see [`PropertyHint`] in the Godot docs.
+    pub fn new(hint: PropertyHint, hint_string: impl Into<GString>) -> Self {
+        Self {
+            hint,
+            hint_string: hint_string.into(),
+        }
+    }
+
     /// Create a new `PropertyHintInfo` with a property hint of [`PROPERTY_HINT_NONE`](PropertyHint::NONE), and no hint string.
     pub fn none() -> Self {
         Self {
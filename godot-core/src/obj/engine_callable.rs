@@ -0,0 +1,138 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::builtin::{StringName, Variant};
+use crate::classes::Object;
+use crate::meta::error::CallError;
+use crate::meta::{FromGodot, ToGodot};
+use crate::obj::{Gd, Inherits};
+
+/// Typed handle for repeatedly calling one method on one object.
+///
+/// Caches the target object and the method's [`StringName`], so that each call skips the string-to-`StringName` conversion that
+/// [`Object::call()`] performs for `&str` method names. Arguments are passed as a tuple `Args` and converted to variants on the stack;
+/// the return value is converted to `Ret`.
+///
+/// This is useful for hot loops that invoke dynamic methods, e.g. methods defined in GDScript. For engine methods with a generated
+/// Rust API, prefer calling those directly.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::obj::EngineCallable;
+/// let node = Node::new_alloc();
+/// let mut has_method = EngineCallable::<(StringName,), bool>::new(node.clone(), "has_method");
+///
+/// assert!(has_method.call((StringName::from("get_name"),)));
+/// # node.free();
+/// ```
+pub struct EngineCallable<Args, Ret> {
+    target: Gd<Object>,
+    method: StringName,
+    _signature: PhantomData<fn(Args) -> Ret>,
+}
+
+impl<Args, Ret> EngineCallable<Args, Ret>
+where
+    Args: CallArgs,
+    Ret: FromGodot,
+{
+    /// Creates a typed callable for `method` on `target`.
+    ///
+    /// The method is not looked up at this point; calling a non-existent method fails at invocation time.
+    pub fn new<T>(target: Gd<T>, method: &'static str) -> Self
+    where
+        T: Inherits<Object>,
+    {
+        Self {
+            target: target.upcast(),
+            method: StringName::from(method),
+            _signature: PhantomData,
+        }
+    }
+
+    /// Calls the method with `args` and converts the result to `Ret`.
+    ///
+    /// # Panics
+    /// If the call fails (e.g. the method does not exist or the arguments mismatch), or if the return value cannot be converted to `Ret`.
+    /// See [`try_call()`][Self::try_call] for a non-panicking version.
+    pub fn call(&mut self, args: Args) -> Ret {
+        self.try_call(args)
+            .unwrap_or_else(|err| panic!("EngineCallable::call() failed: {err}"))
+    }
+
+    /// Calls the method with `args` and converts the result to `Ret`, returning an error on failure.
+    pub fn try_call(&mut self, args: Args) -> Result<Ret, CallError> {
+        let target = &mut self.target;
+        let method = &self.method;
+
+        args.with_variants(|variants| target.call_method::<Ret>(method, variants))
+    }
+
+    /// Object on which the method is called.
+    pub fn target(&self) -> &Gd<Object> {
+        &self.target
+    }
+
+    /// Name of the called method.
+    pub fn method_name(&self) -> &StringName {
+        &self.method
+    }
+}
+
+impl<Args, Ret> Clone for EngineCallable<Args, Ret> {
+    fn clone(&self) -> Self {
+        Self {
+            target: self.target.clone(),
+            method: self.method.clone(),
+            _signature: PhantomData,
+        }
+    }
+}
+
+impl<Args, Ret> fmt::Debug for EngineCallable<Args, Ret> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EngineCallable")
+            .field("target", &self.target)
+            .field("method", &self.method)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Argument tuple accepted by [`EngineCallable`].
+///
+/// Implemented for tuples of up to 8 [`ToGodot`] elements, including the empty tuple `()`.
+pub trait CallArgs {
+    /// Converts the arguments to variants and passes them to `f`, without allocating on the heap.
+    fn with_variants<R>(&self, f: impl FnOnce(&[Variant]) -> R) -> R;
+}
+
+macro_rules! impl_call_args_for_tuple {
+    ($($Pn:ident: $n:tt),*) => {
+        impl<$($Pn: ToGodot,)*> CallArgs for ($($Pn,)*) {
+            fn with_variants<R>(&self, f: impl FnOnce(&[Variant]) -> R) -> R {
+                let variants: &[Variant] = &[$(self.$n.to_variant()),*];
+                f(variants)
+            }
+        }
+    };
+}
+
+impl_call_args_for_tuple!();
+impl_call_args_for_tuple!(P0: 0);
+impl_call_args_for_tuple!(P0: 0, P1: 1);
+impl_call_args_for_tuple!(P0: 0, P1: 1, P2: 2);
+impl_call_args_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3);
+impl_call_args_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4);
+impl_call_args_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5);
+impl_call_args_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6);
+impl_call_args_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6, P7: 7);
@@ -13,7 +13,7 @@ use sys::{static_assert_eq_size_align, SysPtr as _, VariantType};
 
 use crate::builtin::{Callable, NodePath, StringName, Variant};
 use crate::global::PropertyHint;
-use crate::meta::error::{ConvertError, FromFfiError};
+use crate::meta::error::{CallError, ConvertError, FromFfiError};
 use crate::meta::{
     ArrayElement, AsArg, CallContext, ClassName, CowArg, FromGodot, GodotConvert, GodotType,
     ParamType, PropertyHintInfo, RefArg, ToGodot,
@@ -188,6 +188,39 @@ where
     pub fn bind_mut(&mut self) -> GdMut<T> {
         self.raw.bind_mut()
     }
+
+    /// Calls `f` with a shared reference to the user instance, releasing the borrow when `f` returns.
+    ///
+    /// Equivalent to `f(&*self.bind())`, but makes it impossible to keep the [`GdRef`] guard alive beyond the closure.
+    ///
+    /// # Panics
+    /// Same as [`bind()`][Self::bind].
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        let guard = self.bind();
+        f(&guard)
+    }
+
+    /// Calls `f` with an exclusive reference to the user instance, releasing the borrow when `f` returns.
+    ///
+    /// Equivalent to `f(&mut *self.bind_mut())`, but makes it impossible to keep the [`GdMut`] guard alive beyond the closure.
+    /// This is useful for several consecutive calls:
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # #[derive(GodotClass)] #[class(init)] struct Player { speed: f32 }
+    /// # impl Player { fn jump(&mut self) {} }
+    /// # let mut player = Player::new_gd();
+    /// player.with_mut(|p| {
+    ///     p.speed = 100.0;
+    ///     p.jump();
+    /// });
+    /// ```
+    ///
+    /// # Panics
+    /// Same as [`bind_mut()`][Self::bind_mut].
+    pub fn with_mut<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+        let mut guard = self.bind_mut();
+        f(&mut guard)
+    }
 }
 
 /// _The methods in this impl block are available for any `T`._ <br><br>
@@ -298,6 +331,16 @@ impl<T: GodotClass> Gd<T> {
         }
     }
 
+    /// Checks if the dynamic class of the object is exactly `U`, not a subclass of it.
+    ///
+    /// Compares the runtime class name (as returned by [`Object::get_class()`][classes::Object::get_class]) with [`U::class_name()`].
+    /// Unlike [`try_cast()`][Self::try_cast] and GDScript's `is`, this returns `false` for objects of classes derived from `U`.
+    ///
+    /// [`U::class_name()`]: GodotClass::class_name
+    pub fn is_class_exact<U: GodotClass>(&self) -> bool {
+        self.dynamic_class_string() == U::class_name().to_string_name()
+    }
+
     /// **Upcast:** convert into a smart pointer to a base class. Always succeeds.
     ///
     /// Moves out of this value. If you want to create _another_ smart pointer instance,
@@ -479,6 +522,149 @@ impl<T: GodotClass> Gd<T> {
         Callable::from_object_method(self, method_name)
     }
 
+    /// Dynamically calls a method and converts its return value to `R`.
+    ///
+    /// Combines [`Object::try_call()`][classes::Object::try_call] with [`Variant::try_to()`]. If the call succeeds but the result
+    /// cannot be converted, the returned error names `method` and has the [`ConvertError`] as its source.
+    ///
+    /// See also [`call0()`][Self::call0] and [`call1()`][Self::call1] for common arities.
+    pub fn call_method<R: FromGodot>(
+        &mut self,
+        method: impl AsArg<StringName>,
+        args: &[Variant],
+    ) -> Result<R, CallError>
+    where
+        T: Inherits<classes::Object>,
+    {
+        crate::meta::arg_into_ref!(method: StringName);
+
+        let result = self
+            .upcast_mut::<classes::Object>()
+            .try_call(method, args)?;
+
+        result.try_to::<R>().map_err(|err| {
+            let method_name = method.to_string();
+            CallError::failed_return_conversion::<R>(&CallContext::gd::<T>(&method_name), err)
+        })
+    }
+
+    /// Dynamically calls a method without arguments and converts its return value to `R`.
+    ///
+    /// See [`call_method()`][Self::call_method] for details.
+    pub fn call0<R: FromGodot>(&mut self, method: impl AsArg<StringName>) -> Result<R, CallError>
+    where
+        T: Inherits<classes::Object>,
+    {
+        self.call_method(method, &[])
+    }
+
+    /// Dynamically calls a method with one argument and converts its return value to `R`.
+    ///
+    /// See [`call_method()`][Self::call_method] for details.
+    pub fn call1<R: FromGodot, A: ToGodot>(
+        &mut self,
+        method: impl AsArg<StringName>,
+        arg1: A,
+    ) -> Result<R, CallError>
+    where
+        T: Inherits<classes::Object>,
+    {
+        self.call_method(method, &[arg1.to_variant()])
+    }
+
+    /// Connects a signal of this object to a Rust closure, without the need for a receiver object.
+    ///
+    /// The closure receives the signal arguments. The connection does not keep this object alive; it is removed by Godot once the object
+    /// is destroyed. To disconnect earlier, use the returned [`ConnectionHandle`].
+    ///
+    /// Without the `experimental-threads` feature, the closure may only be invoked on the thread that connected it.
+    ///
+    /// # Errors
+    /// If Godot rejects the connection, e.g. because the signal does not exist.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let mut node = Node::new_alloc();
+    /// let handle = node
+    ///     .connect_fn("renamed", |_args| godot_print!("node renamed"))
+    ///     .expect("signal exists");
+    ///
+    /// node.set_name("NewName");
+    /// handle.disconnect();
+    /// ```
+    #[cfg(since_api = "4.2")]
+    pub fn connect_fn<F>(
+        &mut self,
+        signal: impl AsArg<StringName>,
+        function: F,
+    ) -> Result<ConnectionHandle, CallError>
+    where
+        T: Inherits<classes::Object>,
+        F: Fn(&[Variant]) + Send + Sync + 'static,
+    {
+        crate::meta::arg_into_owned!(signal);
+        self.connect_fn_with_flags(signal, function, 0, "connect_fn")
+    }
+
+    /// Like [`connect_fn()`][Self::connect_fn], but the closure is invoked at idle time rather than during the signal emission.
+    ///
+    /// Uses [`ConnectFlags::DEFERRED`](classes::object::ConnectFlags::DEFERRED).
+    #[cfg(since_api = "4.2")]
+    pub fn connect_fn_deferred<F>(
+        &mut self,
+        signal: impl AsArg<StringName>,
+        function: F,
+    ) -> Result<ConnectionHandle, CallError>
+    where
+        T: Inherits<classes::Object>,
+        F: Fn(&[Variant]) + Send + Sync + 'static,
+    {
+        use crate::obj::EngineBitfield as _;
+
+        crate::meta::arg_into_owned!(signal);
+        let flags = classes::object::ConnectFlags::DEFERRED.ord() as u32;
+        self.connect_fn_with_flags(signal, function, flags, "connect_fn_deferred")
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn connect_fn_with_flags<F>(
+        &mut self,
+        signal: StringName,
+        function: F,
+        flags: u32,
+        method_name: &'static str,
+    ) -> Result<ConnectionHandle, CallError>
+    where
+        T: Inherits<classes::Object>,
+        F: Fn(&[Variant]) + Send + Sync + 'static,
+    {
+        let callable_name = crate::builtin::GString::from(format!("{signal} (Rust closure)"));
+        let rust_function = move |args: &[&Variant]| {
+            let args: Vec<Variant> = args.iter().map(|&arg| arg.clone()).collect();
+            function(&args);
+            Ok(Variant::nil())
+        };
+
+        #[cfg(feature = "experimental-threads")]
+        let callable = Callable::from_sync_fn(&callable_name, rust_function);
+        #[cfg(not(feature = "experimental-threads"))]
+        let callable = Callable::from_local_fn(&callable_name, rust_function);
+
+        let object = self.upcast_mut::<classes::Object>();
+        let error = object.connect_ex(&signal, &callable).flags(flags).done();
+        if error != crate::global::Error::OK {
+            let call_ctx = CallContext::gd::<T>(method_name);
+            return Err(CallError::failed_engine_error(&call_ctx, error));
+        }
+
+        Ok(ConnectionHandle {
+            object_id: object.instance_id(),
+            signal,
+            callable,
+        })
+    }
+
     pub(crate) unsafe fn from_obj_sys_or_none(
         ptr: sys::GDExtensionObjectPtr,
     ) -> Result<Self, ConvertError> {
@@ -942,6 +1128,9 @@ impl<T: GodotClass> PartialEq for Gd<T> {
 
 impl<T: GodotClass> Eq for Gd<T> {}
 
+/// Uses Godot's `Object::to_string()`, which yields `<Node3D#1234>` unless the class overrides it.
+///
+/// Freed objects do not panic, but are displayed as `<Freed Object#1234>`.
 impl<T: GodotClass> Display for Gd<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         classes::display_string(self, f)
@@ -964,6 +1153,56 @@ impl<T: GodotClass> std::hash::Hash for Gd<T> {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Closure connections
+
+/// Signal connection to a Rust closure, returned by [`Gd::connect_fn()`] and [`Gd::connect_fn_deferred()`].
+///
+/// Only stores the instance ID of the emitting object, so it does not keep it alive. Dropping the handle does **not** disconnect the
+/// closure; call [`disconnect()`][Self::disconnect] for that.
+#[cfg(since_api = "4.2")]
+#[derive(Clone, Debug)]
+pub struct ConnectionHandle {
+    object_id: InstanceId,
+    signal: StringName,
+    callable: Callable,
+}
+
+#[cfg(since_api = "4.2")]
+impl ConnectionHandle {
+    /// Name of the connected signal.
+    pub fn signal_name(&self) -> &StringName {
+        &self.signal
+    }
+
+    /// The callable wrapping the Rust closure.
+    pub fn callable(&self) -> &Callable {
+        &self.callable
+    }
+
+    /// Returns `true` if the emitting object is still alive and the closure is still connected.
+    pub fn is_connected(&self) -> bool {
+        Gd::<classes::Object>::try_from_instance_id(self.object_id)
+            .is_ok_and(|object| object.is_connected(&self.signal, &self.callable))
+    }
+
+    /// Disconnects the closure from the signal.
+    ///
+    /// Returns `true` if the connection was removed, or `false` if it no longer existed (disconnected or emitting object destroyed).
+    pub fn disconnect(self) -> bool {
+        let Ok(mut object) = Gd::<classes::Object>::try_from_instance_id(self.object_id) else {
+            return false;
+        };
+
+        if !object.is_connected(&self.signal, &self.callable) {
+            return false;
+        }
+
+        object.disconnect(&self.signal, &self.callable);
+        true
+    }
+}
+
 // Gd unwinding across panics does not invalidate any invariants;
 // its mutability is anyway present, in the Godot engine.
 impl<T: GodotClass> std::panic::UnwindSafe for Gd<T> {}
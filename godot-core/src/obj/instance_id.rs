@@ -7,7 +7,7 @@
 
 use crate::meta::error::{ConvertError, FromGodotError};
 use crate::meta::{FromGodot, GodotConvert, ToGodot};
-use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::fmt::{Debug, Display, Formatter, LowerHex, Result as FmtResult};
 use std::num::NonZeroU64;
 
 /// Represents a non-zero instance ID.
@@ -81,6 +81,13 @@ impl Display for InstanceId {
     }
 }
 
+/// Formats the ID as hexadecimal, e.g. `format!("{id:x}")` or `format!("{id:#x}")`.
+impl LowerHex for InstanceId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        LowerHex::fmt(&self.to_u64(), f)
+    }
+}
+
 impl Debug for InstanceId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(f, "InstanceId({})", self.to_i64())
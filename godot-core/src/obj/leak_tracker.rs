@@ -0,0 +1,105 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+
+use godot_ffi::Global;
+
+use crate::classes::RefCounted;
+use crate::obj::Inherits;
+
+/// Number of live instances per tracked class, keyed by the Rust type.
+static LIVE_COUNTS: Global<HashMap<TypeId, u64>> = Global::default();
+
+/// Field that opts a user class into live-instance counting, for detecting memory leaks.
+///
+/// `RefCounted` objects that are part of a reference cycle are never freed, and nothing notifies you about it. Adding a
+/// `LeakTracked<Self>` field to a class counts each instance from construction until Godot frees it; the count can then be queried
+/// with [`LeakTracker`].
+///
+/// The field is initialized through `Default`, so it needs no `#[init]` attribute. It holds no data.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::obj::{LeakTracked, LeakTracker};
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=RefCounted)]
+/// struct Bullet {
+///     target: Option<Gd<Bullet>>,
+///     _tracked: LeakTracked<Self>,
+/// }
+///
+/// // In test teardown:
+/// LeakTracker::<Bullet>::assert_all_freed();
+/// ```
+pub struct LeakTracked<T: Inherits<RefCounted>> {
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: Inherits<RefCounted>> Default for LeakTracked<T> {
+    fn default() -> Self {
+        let mut counts = LIVE_COUNTS.lock();
+        *counts.entry(TypeId::of::<T>()).or_insert(0) += 1;
+
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Inherits<RefCounted>> Drop for LeakTracked<T> {
+    fn drop(&mut self) {
+        let mut counts = LIVE_COUNTS.lock();
+        let count = counts
+            .get_mut(&TypeId::of::<T>())
+            .expect("LeakTracked: class registered on construction");
+
+        *count -= 1;
+    }
+}
+
+impl<T: Inherits<RefCounted>> fmt::Debug for LeakTracked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LeakTracked<{}>", T::class_name())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Queries live-instance counts of classes with a [`LeakTracked`] field.
+///
+/// Classes without such a field always report 0.
+pub struct LeakTracker<T: Inherits<RefCounted>> {
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T: Inherits<RefCounted>> LeakTracker<T> {
+    /// Number of instances of `T` that have been constructed but not yet freed.
+    pub fn live_count() -> u64 {
+        let counts = LIVE_COUNTS.lock();
+        counts.get(&TypeId::of::<T>()).copied().unwrap_or(0)
+    }
+
+    /// ⚠️ Asserts that all instances of `T` have been freed.
+    ///
+    /// # Panics
+    /// If [`live_count()`][Self::live_count] is not 0.
+    pub fn assert_all_freed() {
+        let live_count = Self::live_count();
+
+        assert_eq!(
+            live_count,
+            0,
+            "{live_count} instance(s) of class `{}` are still alive (leaked?)",
+            T::class_name()
+        );
+    }
+}
@@ -13,9 +13,11 @@
 
 mod base;
 mod dyn_gd;
+mod engine_callable;
 mod gd;
 mod guards;
 mod instance_id;
+mod leak_tracker;
 mod onready;
 mod raw_gd;
 mod traits;
@@ -24,9 +26,11 @@ pub(crate) mod rtti;
 
 pub use base::*;
 pub use dyn_gd::DynGd;
+pub use engine_callable::{CallArgs, EngineCallable};
 pub use gd::*;
 pub use guards::{BaseMut, BaseRef, DynGdMut, DynGdRef, GdMut, GdRef};
 pub use instance_id::*;
+pub use leak_tracker::{LeakTracked, LeakTracker};
 pub use onready::*;
 pub use raw_gd::*;
 pub use traits::*;
@@ -187,6 +187,29 @@ pub fn flush_stdout() {
 /// Ensure `T` is an editor plugin.
 pub const fn is_editor_plugin<T: crate::obj::Inherits<crate::classes::EditorPlugin>>() {}
 
+/// Implemented only for `Base<B>`, to check the base field of a user class against its declared base class `B`.
+///
+/// Generated code accesses the base field only through this trait, so that a mismatch is reported once, with a dedicated message.
+#[diagnostic::on_unimplemented(
+    message = "base field has type `{Self}`, but the class declares `{B}` as its base class",
+    label = "expected `Base<{B}>`",
+    note = "either change the field's type or #[class(base = ...)], so that both name the same class"
+)]
+pub trait IsBaseFieldOf<B: crate::obj::GodotClass> {
+    fn from_base(base: crate::obj::Base<B>) -> Self;
+    fn as_base(&self) -> &crate::obj::Base<B>;
+}
+
+impl<B: crate::obj::GodotClass> IsBaseFieldOf<B> for crate::obj::Base<B> {
+    fn from_base(base: crate::obj::Base<B>) -> Self {
+        base
+    }
+
+    fn as_base(&self) -> &crate::obj::Base<B> {
+        self
+    }
+}
+
 // Starting from 4.3, Godot has "runtime classes"; this emulation is no longer needed.
 #[cfg(before_api = "4.3")]
 pub fn is_class_inactive(is_tool: bool) -> bool {
@@ -298,6 +321,20 @@ pub fn handle_varcall_panic<F, R>(
         Err(panic_msg) => CallError::failed_by_user_panic(call_ctx, panic_msg),
     };
 
+    write_varcall_error(call_error, out_err);
+}
+
+/// Like [`handle_varcall_panic`], but for `#[func(no_panic_catch)]`: the call has already happened, panics are not caught.
+pub fn handle_varcall_result<R>(
+    out_err: &mut sys::GDExtensionCallError,
+    result: Result<R, CallError>,
+) {
+    if let Err(call_error) = result {
+        write_varcall_error(call_error, out_err);
+    }
+}
+
+fn write_varcall_error(call_error: CallError, out_err: &mut sys::GDExtensionCallError) {
     let error_id = report_call_error(call_error, true);
 
     // Abuse 'argument' field to store our ID.
@@ -8,6 +8,7 @@
 use std::collections::HashMap;
 use std::{any, ptr};
 
+use crate::builtin::StringName;
 use crate::init::InitLevel;
 use crate::meta::error::{ConvertError, FromGodotError};
 use crate::meta::ClassName;
@@ -43,6 +44,15 @@ fn global_loaded_classes_by_name() -> GlobalGuard<'static, HashMap<ClassName, Cl
     lock_or_panic(&LOADED_CLASSES_BY_NAME, "loaded classes (by name)")
 }
 
+/// Returns a lock to a global map of Rust type names, by Godot class name.
+///
+/// Keyed by the string form of the class name, so that [`class_name_to_rust_name()`] can look up arbitrary names without a `ClassName`.
+fn global_rust_type_names_by_godot_name() -> GlobalGuard<'static, HashMap<String, &'static str>> {
+    static RUST_TYPE_NAMES_BY_GODOT_NAME: Global<HashMap<String, &'static str>> = Global::default();
+
+    lock_or_panic(&RUST_TYPE_NAMES_BY_GODOT_NAME, "Rust type names")
+}
+
 fn global_dyn_traits_by_typeid(
 ) -> GlobalGuard<'static, HashMap<any::TypeId, Vec<DynToClassRelation>>> {
     static DYN_TRAITS_BY_TYPEID: Global<HashMap<any::TypeId, Vec<DynToClassRelation>>> =
@@ -78,6 +88,7 @@ pub struct DynToClassRelation {
 struct ClassRegistrationInfo {
     class_name: ClassName,
     parent_class_name: Option<ClassName>,
+    rust_type_name: Option<&'static str>,
     // Following functions are stored separately, since their order matters.
     register_methods_constants_fn: Option<ErasedRegisterFn>,
     register_properties_fn: Option<ErasedRegisterFn>,
@@ -173,6 +184,7 @@ pub fn register_class<
     register_class_raw(ClassRegistrationInfo {
         class_name: T::class_name(),
         parent_class_name: Some(T::Base::class_name()),
+        rust_type_name: Some(std::any::type_name::<T>()),
         register_methods_constants_fn: None,
         register_properties_fn: None,
         user_register_fn: Some(ErasedRegisterFn {
@@ -217,6 +229,7 @@ pub fn auto_register_classes(init_level: InitLevel) {
     let mut loaded_classes_by_level = global_loaded_classes_by_init_level();
     let mut loaded_classes_by_name = global_loaded_classes_by_name();
     let mut dyn_traits_by_typeid = global_dyn_traits_by_typeid();
+    let mut rust_type_names_by_godot_name = global_rust_type_names_by_godot_name();
 
     for mut info in map.into_values() {
         let class_name = info.class_name;
@@ -244,6 +257,9 @@ pub fn auto_register_classes(init_level: InitLevel) {
             .or_default()
             .push(loaded_class);
 
+        if let Some(rust_type_name) = info.rust_type_name {
+            rust_type_names_by_godot_name.insert(class_name.to_string(), rust_type_name);
+        }
         loaded_classes_by_name.insert(class_name, metadata);
 
         register_class_raw(info);
@@ -257,6 +273,7 @@ pub fn auto_register_classes(init_level: InitLevel) {
 pub fn unregister_classes(init_level: InitLevel) {
     let mut loaded_classes_by_level = global_loaded_classes_by_init_level();
     let mut loaded_classes_by_name = global_loaded_classes_by_name();
+    let mut rust_type_names_by_godot_name = global_rust_type_names_by_godot_name();
     // TODO clean up dyn traits
 
     let loaded_classes_current_level = loaded_classes_by_level
@@ -265,14 +282,28 @@ pub fn unregister_classes(init_level: InitLevel) {
 
     out!("Unregister classes of level {init_level:?}...");
     for class in loaded_classes_current_level.into_iter().rev() {
-        // Remove from other map.
+        // Remove from other maps.
         loaded_classes_by_name.remove(&class.name);
+        rust_type_names_by_godot_name.remove(class.name.to_cow_str().as_ref());
 
         // Unregister from Godot.
         unregister_class_raw(class);
     }
 }
 
+/// Returns the Rust type name of a class registered by this extension, given its Godot class name.
+///
+/// The name is the full path as returned by [`std::any::type_name()`], e.g. `"my_crate::player::Player"`, and is meant for diagnostics
+/// only -- its exact format is not guaranteed. Returns `None` for engine classes, classes from other extensions or scripts, and classes
+/// that are not (or no longer) registered.
+///
+/// See also [`Object::get_class_rust_type()`][crate::classes::Object::get_class_rust_type].
+pub fn class_name_to_rust_name(godot_class: &StringName) -> Option<&'static str> {
+    global_rust_type_names_by_godot_name()
+        .get(&godot_class.to_string())
+        .copied()
+}
+
 #[cfg(feature = "codegen-full")]
 pub fn auto_register_rpcs<T: GodotClass>(object: &mut T) {
     // Find the element that matches our class, and call the closure if it exists.
@@ -342,6 +373,7 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
     match item {
         PluginItem::Struct {
             base_class_name,
+            rust_type_name,
             generated_create_fn,
             generated_recreate_fn,
             register_properties_fn,
@@ -351,10 +383,12 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             is_editor_plugin,
             is_internal,
             is_instantiable,
+            is_abstract,
             #[cfg(all(since_api = "4.3", feature = "register-docs"))]
                 docs: _,
         } => {
             c.parent_class_name = Some(base_class_name);
+            c.rust_type_name = Some(rust_type_name);
             c.default_virtual_fn = default_get_virtual_fn;
             c.register_properties_fn = Some(register_properties_fn);
             c.is_editor_plugin = is_editor_plugin;
@@ -368,6 +402,9 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             //
             // See also: https://github.com/godotengine/godot/pull/58972
             c.godot_params.is_abstract = sys::conv::bool_to_sys(!is_instantiable);
+
+            // #[class(abstract)] maps to such a "virtual" class: it keeps its constructor, which is needed to instantiate subclasses.
+            c.godot_params.is_virtual = sys::conv::bool_to_sys(is_abstract);
             c.godot_params.free_instance_func = Some(free_fn);
 
             fill_into(
@@ -617,6 +654,7 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
     ClassRegistrationInfo {
         class_name,
         parent_class_name: None,
+        rust_type_name: None,
         register_methods_constants_fn: None,
         register_properties_fn: None,
         user_register_fn: None,
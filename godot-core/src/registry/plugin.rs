@@ -82,6 +82,9 @@ pub enum PluginItem {
     Struct {
         base_class_name: ClassName,
 
+        /// Rust type name of the class, as returned by [`std::any::type_name()`].
+        rust_type_name: &'static str,
+
         /// Godot low-level `create` function, wired up to library-generated `init`.
         generated_create_fn: Option<
             unsafe extern "C" fn(
@@ -124,6 +127,9 @@ pub enum PluginItem {
 
         /// Whether the class has a default constructor.
         is_instantiable: bool,
+
+        /// Whether `#[class(abstract)]` was used.
+        is_abstract: bool,
         #[cfg(all(since_api = "4.3", feature = "register-docs"))]
         docs: Option<StructDocs>,
     },
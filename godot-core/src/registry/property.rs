@@ -115,6 +115,29 @@ pub trait Export: Var {
 ///     array: Array<Gd<Object>>,
 /// }
 /// ```
+///
+/// Export hints restricted to a certain type cannot be used with other field types:
+/// ```compile_fail
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Foo {
+///     #[export(node_path_filter = (type = "Node3D"))]
+///     path: GString,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Foo {
+///     #[export(multiline)]
+///     text: StringName,
+/// }
+/// ```
 #[allow(dead_code)]
 fn export_doctests() {}
 
@@ -376,6 +399,19 @@ pub mod export_info_functions {
         }
     }
 
+    /// Equivalent to `@export_node_path` in Godot.
+    ///
+    /// `types` is a comma-separated list of class names that the path may point to, e.g. `"CharacterBody3D,RigidBody3D"`.
+    pub fn export_node_path<S: AsRef<str>>(types: S) -> PropertyHintInfo {
+        PropertyHintInfo {
+            hint: PropertyHint::NODE_PATH_VALID_TYPES,
+            hint_string: types.as_ref().into(),
+        }
+    }
+
+    /// Equivalent to `@export_placeholder` in Godot.
+    ///
+    /// Shows `placeholder` as hint text in the editor while the string is empty.
     pub fn export_placeholder<S: AsRef<str>>(placeholder: S) -> PropertyHintInfo {
         PropertyHintInfo {
             hint: PropertyHint::PLACEHOLDER_TEXT,
@@ -517,6 +553,15 @@ mod export_impls {
     impl_property_by_godot_convert!(u16);
     impl_property_by_godot_convert!(u8);
 
+    // Durations are stored as float seconds in Godot.
+    impl_property_by_godot_convert!(std::time::Duration, no_export);
+
+    impl Export for std::time::Duration {
+        fn export_hint() -> PropertyHintInfo {
+            PropertyHintInfo::type_name::<f64>()
+        }
+    }
+
     // Callables and Signals are useless when exported to the editor, so we only need to make them available as
     // properties.
     impl_property_by_godot_convert!(Callable, no_export);
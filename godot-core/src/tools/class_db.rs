@@ -0,0 +1,113 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+
+use crate::builtin::{Dictionary, GString, StringName, Variant, VariantArray, VariantType};
+use crate::global::{MethodFlags, PropertyHint, PropertyUsageFlags};
+use crate::obj::{EngineBitfield, EngineEnum};
+
+/// Typed description of a method, as reported by [`ClassDb::class_methods()`][crate::classes::ClassDb::class_methods].
+///
+/// Godot's [`ClassDb::class_get_method_list()`][crate::classes::ClassDb::class_get_method_list] returns an array of untyped
+/// dictionaries. [`from_dictionary()`][Self::from_dictionary] converts one of them into this struct.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ClassDbMethod {
+    /// Name of the method.
+    pub name: StringName,
+
+    /// Name and type of each parameter, in order. Parameters accepting any `Variant` have type [`VariantType::NIL`].
+    pub params: Vec<(StringName, VariantType)>,
+
+    /// Default values of the trailing parameters.
+    pub default_args: Vec<Variant>,
+
+    /// Return type; [`VariantType::NIL`] for both `void` and `Variant` returns.
+    pub return_type: VariantType,
+
+    /// Method flags, e.g. whether the method is `const`, virtual or vararg.
+    pub flags: MethodFlags,
+}
+
+impl ClassDbMethod {
+    /// Converts a method dictionary, as returned by `ClassDB.class_get_method_list()` or `Object.get_method_list()`.
+    ///
+    /// # Panics
+    /// If the dictionary does not have the structure of a method description.
+    pub fn from_dictionary(method: &Dictionary) -> Self {
+        let params = method
+            .at("args")
+            .to::<VariantArray>()
+            .iter_shared()
+            .map(|arg| {
+                let arg = arg.to::<Dictionary>();
+                (arg.at("name").to(), variant_type_at(&arg, "type"))
+            })
+            .collect();
+
+        let default_args = method
+            .at("default_args")
+            .to::<VariantArray>()
+            .iter_shared()
+            .collect();
+
+        Self {
+            name: method.at("name").to(),
+            params,
+            default_args,
+            return_type: variant_type_at(&method.at("return").to(), "type"),
+            flags: MethodFlags::from_ord(method.at("flags").to()),
+        }
+    }
+}
+
+/// Typed description of a property, as reported by [`ClassDb::class_properties()`][crate::classes::ClassDb::class_properties].
+///
+/// Godot's [`ClassDb::class_get_property_list()`][crate::classes::ClassDb::class_get_property_list] returns an array of untyped
+/// dictionaries. [`from_dictionary()`][Self::from_dictionary] converts one of them into this struct.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ClassDbProperty {
+    /// Name of the property. For groups and categories, this is their display name.
+    pub name: StringName,
+
+    /// Type of the property; [`VariantType::NIL`] for `Variant` properties, groups and categories.
+    pub variant_type: VariantType,
+
+    /// For object properties, the name of the class; otherwise empty.
+    pub class_name: StringName,
+
+    /// Editor hint, refined by [`hint_string`][Self::hint_string].
+    pub hint: PropertyHint,
+
+    /// Hint-specific information, e.g. the enumerator names for [`PropertyHint::ENUM`].
+    pub hint_string: GString,
+
+    /// How the property is used, e.g. whether it is stored or shown in the editor.
+    pub usage: PropertyUsageFlags,
+}
+
+impl ClassDbProperty {
+    /// Converts a property dictionary, as returned by `ClassDB.class_get_property_list()` or `Object.get_property_list()`.
+    ///
+    /// # Panics
+    /// If the dictionary does not have the structure of a property description.
+    pub fn from_dictionary(property: &Dictionary) -> Self {
+        Self {
+            name: property.at("name").to(),
+            variant_type: variant_type_at(property, "type"),
+            class_name: property.at("class_name").to(),
+            hint: PropertyHint::from_ord(property.at("hint").to()),
+            hint_string: property.at("hint_string").to(),
+            usage: PropertyUsageFlags::from_ord(property.at("usage").to()),
+        }
+    }
+}
+
+fn variant_type_at(dict: &Dictionary, key: &str) -> VariantType {
+    let sys_type = dict.at(key).to::<i64>() as sys::GDExtensionVariantType;
+    VariantType::from_sys(sys_type)
+}
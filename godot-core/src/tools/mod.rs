@@ -10,10 +10,20 @@
 //! Contains functionality that extends existing Godot classes and functions, to make them more versatile
 //! or better integrated with Rust.
 
+mod class_db;
 mod gfile;
+mod ray3d;
+#[cfg(feature = "codegen-full")]
+mod raycast;
 mod save_load;
+mod scene_builder;
 mod translate;
 
+pub use class_db::*;
 pub use gfile::*;
+pub use ray3d::*;
+#[cfg(feature = "codegen-full")]
+pub use raycast::*;
 pub use save_load::*;
+pub use scene_builder::*;
 pub use translate::*;
@@ -0,0 +1,39 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{real, Plane, Vector3};
+
+/// Half-line in 3D space, starting at `origin` and extending infinitely along `direction`.
+///
+/// Returned by [`Viewport::project_mouse_ray()`][crate::classes::Viewport::project_mouse_ray], to map screen positions to the 3D world.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Ray3D {
+    /// Start point of the ray.
+    pub origin: Vector3,
+
+    /// Direction of the ray. Normalized when obtained from a camera projection.
+    pub direction: Vector3,
+}
+
+impl Ray3D {
+    /// Creates a ray starting at `origin` and extending along `direction`.
+    pub fn new(origin: Vector3, direction: Vector3) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Returns the point at `distance` along the ray, measured in multiples of `direction`.
+    pub fn at(self, distance: real) -> Vector3 {
+        self.origin + self.direction * distance
+    }
+
+    /// Finds the intersection point of the ray with `plane`.
+    ///
+    /// Returns `None` if the ray is parallel to the plane or points away from it. See [`Plane::intersect_ray()`].
+    pub fn intersect_plane(self, plane: Plane) -> Option<Vector3> {
+        plane.intersect_ray(self.origin, self.direction)
+    }
+}
@@ -0,0 +1,69 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Dictionary, Rid, Vector3};
+use crate::classes::Object;
+use crate::obj::{Gd, InstanceId};
+
+/// Typed result of a 3D ray cast.
+///
+/// Godot's [`PhysicsDirectSpaceState3D::intersect_ray()`][crate::classes::PhysicsDirectSpaceState3D::intersect_ray] returns an untyped
+/// `Dictionary`, which is empty if nothing was hit. [`from_dictionary()`][Self::from_dictionary] converts it into this struct.
+///
+/// See also [`RayCast3D::immediate_cast()`][crate::classes::RayCast3D::immediate_cast] for one-shot ray casts.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RaycastHit {
+    /// Intersection point, in global coordinates.
+    pub position: Vector3,
+
+    /// Surface normal of the collider at the intersection point. Zero if the ray started inside the shape.
+    pub normal: Vector3,
+
+    /// The colliding object, or `None` if it is no longer alive.
+    pub collider: Option<Gd<Object>>,
+
+    /// Instance ID of the colliding object.
+    pub collider_id: Option<InstanceId>,
+
+    /// Physics-server RID of the colliding object.
+    pub rid: Rid,
+
+    /// Index of the colliding shape within the collider.
+    pub shape: i32,
+
+    /// Index of the intersected face, for concave polygon shapes; otherwise `-1`.
+    pub face_index: i32,
+}
+
+impl RaycastHit {
+    /// Converts the result dictionary of `intersect_ray()`.
+    ///
+    /// Returns `None` if the dictionary is empty, i.e. the ray did not hit anything.
+    ///
+    /// # Panics
+    /// If the dictionary is not empty, but does not have the structure of an `intersect_ray()` result.
+    pub fn from_dictionary(result: &Dictionary) -> Option<Self> {
+        if result.is_empty() {
+            return None;
+        }
+
+        let face_index = match result.get("face_index") {
+            Some(index) => index.to::<i32>(),
+            None => -1, // Not present in older Godot versions.
+        };
+
+        Some(Self {
+            position: result.at("position").to(),
+            normal: result.at("normal").to(),
+            collider: result.at("collider").to(),
+            collider_id: InstanceId::try_from_i64(result.at("collider_id").to()),
+            rid: result.at("rid").to(),
+            shape: result.at("shape").to(),
+            face_index,
+        })
+    }
+}
@@ -0,0 +1,116 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::classes::Node;
+use crate::meta::ToGodot;
+use crate::obj::{Gd, Inherits, NewAlloc};
+
+/// Fluent API to construct a node tree in Rust.
+///
+/// Each node is allocated and named immediately, but nodes are only attached to their parents in [`build()`][Self::build]. This happens
+/// top-down and in declaration order, like when Godot instantiates a [`PackedScene`][crate::classes::PackedScene].
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::tools::SceneBuilder;
+///
+/// let root: Gd<Node3D> = SceneBuilder::<Node3D>::new("Player")
+///     .child::<Node3D>("Pivot", |pivot| {
+///         pivot
+///             .configure(|node| node.set_position(Vector3::new(0.0, 1.5, 0.0)))
+///             .child::<Node3D>("Camera", |camera| camera)
+///     })
+///     .child::<Node3D>("Marker", |marker| marker.set_property("visible", false))
+///     .build();
+/// ```
+///
+/// Nodes are manually managed: if the builder is dropped without calling `build()`, they are leaked. The result of `build()` must be added
+/// to the scene tree or freed.
+#[must_use = "nodes are only assembled into a tree by build()"]
+pub struct SceneBuilder<T: Inherits<Node>> {
+    node: Gd<T>,
+    children: Vec<SceneBuilder<Node>>,
+}
+
+impl<T> SceneBuilder<T>
+where
+    T: Inherits<Node> + NewAlloc,
+{
+    /// Allocates a new node of type `T` with the given name.
+    pub fn new(name: &str) -> Self {
+        Self::from_node(T::new_alloc(), name)
+    }
+}
+
+impl<T: Inherits<Node>> SceneBuilder<T> {
+    /// Starts building from an existing node, which is renamed to `name`.
+    pub fn from_node(mut node: Gd<T>, name: &str) -> Self {
+        node.upcast_mut::<Node>().set_name(name);
+
+        Self {
+            node,
+            children: Vec::new(),
+        }
+    }
+
+    /// Adds a child node of type `C`, which can be set up (including its own children) in `build_child`.
+    pub fn child<C>(
+        mut self,
+        name: &str,
+        build_child: impl FnOnce(SceneBuilder<C>) -> SceneBuilder<C>,
+    ) -> Self
+    where
+        C: Inherits<Node> + NewAlloc,
+    {
+        let child = build_child(SceneBuilder::new(name));
+        self.children.push(child.upcast());
+        self
+    }
+
+    /// Sets a property on the node, by name. See [`Object::set()`][crate::classes::Object::set].
+    ///
+    /// Prefer [`configure()`][Self::configure] with typed setters when the property is known at compile time.
+    pub fn set_property(mut self, property: &str, value: impl ToGodot) -> Self {
+        self.node
+            .upcast_mut::<Node>()
+            .set(property, &value.to_variant());
+        self
+    }
+
+    /// Modifies the node directly, e.g. through its typed setters.
+    pub fn configure(mut self, f: impl FnOnce(&mut Gd<T>)) -> Self {
+        f(&mut self.node);
+        self
+    }
+
+    /// Assembles the tree and returns its root node.
+    pub fn build(self) -> Gd<T> {
+        let mut parent = self.node.clone().upcast::<Node>();
+        attach_children(&mut parent, self.children);
+
+        self.node
+    }
+
+    fn upcast(self) -> SceneBuilder<Node> {
+        SceneBuilder {
+            node: self.node.upcast(),
+            children: self.children,
+        }
+    }
+}
+
+/// Attaches each child before its own children, mirroring the order of `PackedScene` instantiation.
+fn attach_children(parent: &mut Gd<Node>, children: Vec<SceneBuilder<Node>>) {
+    for SceneBuilder {
+        node: mut child,
+        children: grandchildren,
+    } in children
+    {
+        parent.add_child(&child);
+        attach_children(&mut child, grandchildren);
+    }
+}
@@ -6,11 +6,14 @@
  */
 
 use crate::class::{FieldExport, FieldVar};
-use proc_macro2::{Ident, Span, TokenStream};
-use quote::ToTokens;
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::{format_ident, ToTokens};
 
 pub struct Field {
+    /// Field name; for tuple structs, a placeholder like `field_0` that is only used in diagnostics.
     pub name: Ident,
+    /// Position in a tuple struct, or `None` for named fields.
+    pub tuple_index: Option<usize>,
     pub ty: venial::TypeExpr,
     pub default_val: Option<FieldDefault>,
     pub var: Option<FieldVar>,
@@ -25,6 +28,7 @@ impl Field {
     pub fn new(field: &venial::NamedField) -> Self {
         Self {
             name: field.name.clone(),
+            tuple_index: None,
             ty: field.ty.clone(),
             default_val: None,
             var: None,
@@ -35,6 +39,29 @@ impl Field {
             span: field.span(),
         }
     }
+
+    pub fn new_tuple(index: usize, field: &venial::TupleField) -> Self {
+        Self {
+            name: format_ident!("field_{index}"),
+            tuple_index: Some(index),
+            ty: field.ty.clone(),
+            default_val: None,
+            var: None,
+            export: None,
+            is_onready: false,
+            #[cfg(feature = "register-docs")]
+            attributes: field.attributes.clone(),
+            span: field.span(),
+        }
+    }
+
+    /// Tokens to access the field, as in `self.#member` or `Self { #member: value }`.
+    pub fn member(&self) -> TokenStream {
+        match self.tuple_index {
+            Some(index) => Literal::usize_unsuffixed(index).into_token_stream(),
+            None => self.name.to_token_stream(),
+        }
+    }
 }
 
 pub struct Fields {
@@ -6,27 +6,45 @@
  */
 
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
+use quote::{quote, quote_spanned};
 use std::collections::{HashMap, HashSet};
 
-use crate::util::{KvParser, ListParser};
+use crate::util;
+use crate::util::{bail, KvParser, ListParser};
 use crate::ParseResult;
 
 pub struct FieldExport {
     pub export_type: ExportType,
+    /// `#[export(no_save)]`: show in the editor, but do not store the property.
+    pub no_save: Option<Ident>,
     pub span: Span,
 }
 
 impl FieldExport {
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         let span = parser.span();
+        let no_save = parser.handle_alone_with_span("no_save")?;
         let export_type = ExportType::new_from_kv(parser)?;
-        Ok(Self { export_type, span })
+        Ok(Self {
+            export_type,
+            no_save,
+            span,
+        })
     }
 
     pub fn to_export_hint(&self) -> Option<TokenStream> {
         self.export_type.to_export_hint()
     }
+
+    /// For exports restricted to one property type, returns a statement that fails to compile if `field_type` is different.
+    pub fn to_field_type_check(&self, field_type: &venial::TypeExpr) -> Option<TokenStream> {
+        let expected_type = self.export_type.required_field_type()?;
+
+        // Span of the #[export] attribute, so the type mismatch is reported there.
+        Some(quote_spanned! { self.span=>
+            let _: fn(#field_type) -> #expected_type = |field| field;
+        })
+    }
 }
 
 /// Store info from `#[export]` attribute.
@@ -116,6 +134,13 @@ pub enum ExportType {
     /// - `GLOBAL_DIR`
     File { global: bool, kind: FileKind },
 
+    /// ### GDScript annotations
+    /// - `@export_node_path`
+    ///
+    /// ### Property hints
+    /// - `NODE_PATH_VALID_TYPES`
+    NodePath { types: String },
+
     /// ### GDScript annotations
     /// - `@export_multiline`
     ///
@@ -133,6 +158,8 @@ pub enum ExportType {
     /// ### GDScript annotations
     /// - `@export_color_no_alpha`
     ///
+    /// Also available as `#[export(alpha_in_inspector = false)]`.
+    ///
     /// ### Property hints
     /// - `COLOR_NO_ALPHA`
     ColorNoAlpha,
@@ -258,6 +285,10 @@ impl ExportType {
             });
         }
 
+        if let Some(list_parser) = parser.handle_list("node_path_filter")? {
+            return Self::new_node_path_filter(list_parser, parser.span());
+        }
+
         if parser.handle_alone("multiline")? {
             return Ok(Self::Multiline);
         }
@@ -270,6 +301,15 @@ impl ExportType {
             return Ok(Self::ColorNoAlpha);
         }
 
+        // Synonym for `color_no_alpha`; `alpha_in_inspector = true` is the default color picker.
+        if let Some(alpha_in_inspector) = parser.handle_bool("alpha_in_inspector")? {
+            return Ok(if alpha_in_inspector {
+                Self::Default
+            } else {
+                Self::ColorNoAlpha
+            });
+        }
+
         Ok(Self::Default)
     }
 
@@ -365,6 +405,31 @@ impl ExportType {
         })
     }
 
+    fn new_node_path_filter(mut parser: ListParser, span: Span) -> ParseResult<Self> {
+        let Some((_key, Some(value))) = parser.next_allowed_key_optional_value(&[], &["type"])?
+        else {
+            return bail!(span, "expected `node_path_filter = (type = \"ClassName\")`");
+        };
+        parser.finish()?;
+
+        let literal = value.as_literal()?;
+        let Some(types) = util::parse_string_literal(&literal) else {
+            return bail!(
+                literal,
+                "expected string literal with comma-separated class names"
+            );
+        };
+
+        let types = types.split(',').map(str::trim).collect::<Vec<_>>();
+        if types.iter().any(|class_name| class_name.is_empty()) {
+            return bail!(literal, "class names in `type` must not be empty");
+        }
+
+        Ok(Self::NodePath {
+            types: types.join(","),
+        })
+    }
+
     fn new_flags(mut parser: ListParser) -> ParseResult<Self> {
         let mut bits = Vec::new();
 
@@ -505,16 +570,33 @@ impl ExportType {
                 quote_export_func! { export_file_inner(#global, #filter) }
             }
 
+            Self::NodePath { types } => quote_export_func! { export_node_path(#types) },
+
             Self::Multiline => quote_export_func! { export_multiline() },
 
             Self::PlaceholderText { placeholder } => quote_export_func! {
                 export_placeholder(#placeholder)
             },
+
             Self::ColorNoAlpha => quote_export_func! { export_color_no_alpha() },
         }
     }
 }
 
+impl ExportType {
+    /// The only field type this export can be applied to, if restricted.
+    fn required_field_type(&self) -> Option<TokenStream> {
+        match self {
+            Self::NodePath { .. } => Some(quote! { ::godot::builtin::NodePath }),
+            Self::Multiline | Self::PlaceholderText { .. } => {
+                Some(quote! { ::godot::builtin::GString })
+            }
+            Self::ColorNoAlpha => Some(quote! { ::godot::builtin::Color }),
+            _ => None,
+        }
+    }
+}
+
 /// The dimension of a `@export_flags_{dimension}_{layer}` annotation.
 pub enum LayerDimension {
     _2d,
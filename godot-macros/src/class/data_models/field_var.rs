@@ -12,7 +12,7 @@ use crate::class::{
     into_signature_info, make_existence_check, make_method_registration, Field, FieldHint,
     FuncDefinition,
 };
-use crate::util::KvParser;
+use crate::util::{bail, ident, KvParser};
 use crate::{util, ParseResult};
 
 /// Store info from `#[var]` attribute.
@@ -33,7 +33,8 @@ impl FieldVar {
     /// - `set = expr`
     /// - `hint = ident`
     /// - `hint_string = expr`
-    /// - `usage_flags =
+    /// - `usage_flags = [ident, ...]`
+    /// - `save`
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         let span = parser.span();
         let mut getter = GetterSetter::parse(parser, "get")?;
@@ -54,7 +55,7 @@ impl FieldVar {
             FieldHint::Inferred
         };
 
-        let usage_flags = if let Some(mut parser) = parser.handle_array("usage_flags")? {
+        let mut usage_flags = if let Some(mut parser) = parser.handle_array("usage_flags")? {
             let mut flags = Vec::new();
 
             while let Some(flag) = parser.next_ident()? {
@@ -68,6 +69,15 @@ impl FieldVar {
             UsageFlags::Inferred
         };
 
+        // #[var(save)]
+        if let Some(key) = parser.handle_alone_with_span("save")? {
+            if !usage_flags.is_inferred() {
+                return bail!(key, "#[var(save)] cannot be combined with `usage_flags`");
+            }
+
+            usage_flags = UsageFlags::Custom(vec![ident("STORAGE")]);
+        }
+
         Ok(FieldVar {
             getter,
             setter,
@@ -220,6 +230,7 @@ impl GetterSetterImpl {
                 registered_name: None,
                 is_script_virtual: false,
                 rpc_info: None,
+                no_panic_catch: false,
             },
         );
 
@@ -29,6 +29,9 @@ pub struct FuncDefinition {
 
     /// Information about the RPC configuration, if provided.
     pub rpc_info: Option<RpcAttr>,
+
+    /// True if panics should not be caught at the FFI boundary (`#[func(no_panic_catch)]`).
+    pub no_panic_catch: bool,
 }
 
 /// Returns a C function which acts as the callback when a virtual method of this instance is invoked.
@@ -98,8 +101,9 @@ pub fn make_method_registration(
     };
 
     let call_ctx = make_call_context(&class_name_str, &method_name_str);
-    let varcall_fn_decl = make_varcall_fn(&call_ctx, &forwarding_closure);
-    let ptrcall_fn_decl = make_ptrcall_fn(&call_ctx, &forwarding_closure);
+    let no_panic_catch = func_definition.no_panic_catch;
+    let varcall_fn_decl = make_varcall_fn(&call_ctx, &forwarding_closure, no_panic_catch);
+    let ptrcall_fn_decl = make_ptrcall_fn(&call_ctx, &forwarding_closure, no_panic_catch);
 
     // String literals II
     let param_ident_strs = signature_info
@@ -414,9 +418,30 @@ fn make_method_flags(
 }
 
 /// Generate code for a C FFI function that performs a varcall.
-fn make_varcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> TokenStream {
+fn make_varcall_fn(
+    call_ctx: &TokenStream,
+    wrapped_method: &TokenStream,
+    no_panic_catch: bool,
+) -> TokenStream {
     let invocation = make_varcall_invocation(wrapped_method);
 
+    let body = if no_panic_catch {
+        quote! {
+            ::godot::private::handle_varcall_result(
+                &mut *err,
+                #invocation
+            );
+        }
+    } else {
+        quote! {
+            ::godot::private::handle_varcall_panic(
+                &call_ctx,
+                &mut *err,
+                || #invocation
+            );
+        }
+    };
+
     // TODO reduce amount of code generated, by delegating work to a library function. Could even be one that produces this function pointer.
     quote! {
         unsafe extern "C" fn varcall_fn(
@@ -428,27 +453,25 @@ fn make_varcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> Toke
             err: *mut sys::GDExtensionCallError,
         ) {
             let call_ctx = #call_ctx;
-            ::godot::private::handle_varcall_panic(
-                &call_ctx,
-                &mut *err,
-                || #invocation
-            );
+            #body
         }
     }
 }
 
 /// Generate code for a C FFI function that performs a ptrcall.
-fn make_ptrcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> TokenStream {
+fn make_ptrcall_fn(
+    call_ctx: &TokenStream,
+    wrapped_method: &TokenStream,
+    no_panic_catch: bool,
+) -> TokenStream {
     let invocation = make_ptrcall_invocation(wrapped_method, false);
 
-    quote! {
-        unsafe extern "C" fn ptrcall_fn(
-            _method_data: *mut std::ffi::c_void,
-            instance_ptr: sys::GDExtensionClassInstancePtr,
-            args_ptr: *const sys::GDExtensionConstTypePtr,
-            ret: sys::GDExtensionTypePtr,
-        ) {
-            let call_ctx = #call_ctx;
+    let body = if no_panic_catch {
+        quote! {
+            #invocation;
+        }
+    } else {
+        quote! {
             let _success = ::godot::private::handle_panic(
                 || &call_ctx,
                 || #invocation
@@ -458,6 +481,18 @@ fn make_ptrcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> Toke
             //     // TODO set return value to T::default()?
             // }
         }
+    };
+
+    quote! {
+        unsafe extern "C" fn ptrcall_fn(
+            _method_data: *mut std::ffi::c_void,
+            instance_ptr: sys::GDExtensionClassInstancePtr,
+            args_ptr: *const sys::GDExtensionConstTypePtr,
+            ret: sys::GDExtensionTypePtr,
+        ) {
+            let call_ctx = #call_ctx;
+            #body
+        }
     }
 }
 
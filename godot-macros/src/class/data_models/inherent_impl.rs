@@ -61,6 +61,7 @@ struct FuncAttr {
     pub rename: Option<String>,
     pub is_virtual: bool,
     pub has_gd_self: bool,
+    pub no_panic_catch: bool,
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -69,9 +70,12 @@ pub struct InherentImplAttr {
     /// For implementation reasons, there can be a single 'primary' impl block and 0 or more 'secondary' impl blocks.
     /// For now, this is controlled by a key in the 'godot_api' attribute.
     pub secondary: bool,
+
+    /// Path of a user-defined trait, for `#[godot_api(trait = MyTrait)] impl MyTrait for MyType`.
+    pub user_trait: Option<TokenStream>,
 }
 
-/// Codegen for `#[godot_api] impl MyType`
+/// Codegen for `#[godot_api] impl MyType`, as well as `#[godot_api(trait = MyTrait)] impl MyTrait for MyType`
 pub fn transform_inherent_impl(
     meta: InherentImplAttr,
     mut impl_block: venial::Impl,
@@ -81,7 +85,9 @@ pub fn transform_inherent_impl(
     let prv = quote! { ::godot::private };
 
     // Can add extra functions to the end of the impl block.
-    let (funcs, signals) = process_godot_fns(&class_name, &mut impl_block, meta.secondary)?;
+    let is_trait_impl = meta.user_trait.is_some();
+    let (funcs, signals) =
+        process_godot_fns(&class_name, &mut impl_block, meta.secondary, is_trait_impl)?;
     let consts = process_godot_constants(&mut impl_block)?;
 
     #[cfg(all(feature = "register-docs", since_api = "4.3"))]
@@ -106,14 +112,29 @@ pub fn transform_inherent_impl(
     let method_storage_name = format_ident!("__registration_methods_{class_name}");
     let constants_storage_name = format_ident!("__registration_constants_{class_name}");
 
+    // Methods and constants of a user trait are referred to as `Class::item`, which requires the trait to be in scope.
+    let trait_import = match &meta.user_trait {
+        Some(user_trait) => {
+            // `use` does not accept generic arguments, e.g. for `trait = Saveable<i32>`.
+            let trait_path = util::strip_generic_args(user_trait.clone());
+            quote! {
+                #[allow(unused_imports)]
+                use #trait_path as _;
+            }
+        }
+        None => TokenStream::new(),
+    };
+
     let fill_storage = quote! {
         ::godot::sys::plugin_execute_pre_main!({
             #method_storage_name.lock().unwrap().push(|| {
+                #trait_import
                 #( #method_registrations )*
                 #( #signal_registrations )*
             });
 
             #constants_storage_name.lock().unwrap().push(|| {
+                #trait_import
                 #constant_registration
             });
         });
@@ -194,6 +215,7 @@ fn process_godot_fns(
     class_name: &Ident,
     impl_block: &mut venial::Impl,
     is_secondary_impl: bool,
+    is_trait_impl: bool,
 ) -> ParseResult<(Vec<FuncDefinition>, Vec<SignalDefinition>)> {
     let mut func_definitions = vec![];
     let mut signal_definitions = vec![];
@@ -225,6 +247,15 @@ fn process_godot_fns(
 
         match attr.ty {
             ItemAttrType::Func(func, rpc_info) => {
+                if func.is_virtual && is_trait_impl {
+                    // Virtual functions add extra methods to the impl block, which is not possible for trait impls.
+                    return bail_attr(
+                        attr.attr_name,
+                        "key `virtual` is not supported in #[godot_api(trait = ...)] impl blocks",
+                        function,
+                    );
+                }
+
                 let external_attributes = function.attributes.clone();
 
                 // Signatures are the same thing without body.
@@ -281,6 +312,7 @@ fn process_godot_fns(
                     registered_name,
                     is_script_virtual: func.is_virtual,
                     rpc_info,
+                    no_panic_catch: func.no_panic_catch,
                 });
             }
 
@@ -291,6 +323,12 @@ fn process_godot_fns(
                         function,
                     );
                 }
+                if is_trait_impl {
+                    return attr.bail(
+                        "#[signal] is not supported in #[godot_api(trait = ...)] impl blocks",
+                        function,
+                    );
+                }
                 if function.return_ty.is_some() {
                     return attr.bail("return types in #[signal] are not supported", function);
                 }
@@ -482,12 +520,16 @@ where
                 // #[func(gd_self)]
                 let has_gd_self = parser.handle_alone("gd_self")?;
 
+                // #[func(no_panic_catch)]
+                let no_panic_catch = parser.handle_alone("no_panic_catch")?;
+
                 parser.finish()?;
 
                 AttrParseResult::Func(FuncAttr {
                     rename,
                     is_virtual,
                     has_gd_self,
+                    no_panic_catch,
                 })
             }
 
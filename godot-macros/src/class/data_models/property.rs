@@ -8,6 +8,7 @@
 //! Parsing the `var` and `export` attributes on fields.
 
 use crate::class::{Field, FieldVar, Fields, GetSet, GetterSetterImpl, UsageFlags};
+use crate::util;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
@@ -77,7 +78,9 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
         let registration_fn;
 
         if let Some(export) = export {
-            if usage_flags.is_inferred() {
+            if export.no_save.is_some() {
+                usage_flags = UsageFlags::Custom(vec![util::ident("EDITOR")]);
+            } else if usage_flags.is_inferred() {
                 usage_flags = UsageFlags::InferredExport;
             }
 
@@ -145,7 +148,12 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             &mut export_tokens,
         );
 
+        let field_type_check = export
+            .as_ref()
+            .and_then(|export| export.to_field_type_check(field_type));
+
         export_tokens.push(quote! {
+            #field_type_check
             ::godot::register::private::#registration_fn::<#class_name, #field_type>(
                 #field_name,
                 #getter_name,
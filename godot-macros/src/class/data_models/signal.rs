@@ -7,7 +7,7 @@
 
 use crate::util;
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
 
 /// Holds information known from a signal's definition
 pub struct SignalDefinition {
@@ -42,6 +42,14 @@ pub fn make_signal_registrations(
             };
         }
 
+        // Check each parameter type individually, with its own span, so that an unsupported type is reported at the parameter and not as
+        // an unsatisfied `VarcallSignatureTuple` bound on the whole signature.
+        let param_type_checks = param_types.iter().map(|ty| {
+            quote_spanned! { ty.span()=>
+                __signal_param_must_be_godot_compatible::<#ty>();
+            }
+        });
+
         let signature_tuple = util::make_signature_tuple_type(&quote! { () }, &param_types);
         let indexes = 0..param_types.len();
         let param_array_decl = quote! {
@@ -65,6 +73,12 @@ pub fn make_signal_registrations(
         let signal_parameters = param_array_decl;
 
         let signal_registration = quote! {
+            #(#signal_cfg_attrs)*
+            {
+                fn __signal_param_must_be_godot_compatible<T: ::godot::meta::ToGodot + ::godot::meta::FromGodot>() {}
+                #( #param_type_checks )*
+            }
+
             #(#signal_cfg_attrs)*
             unsafe {
                 use ::godot::sys;
@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, Punct, TokenStream};
+use proc_macro2::{Ident, TokenStream, TokenTree};
 use quote::{format_ident, quote, quote_spanned};
 
 use crate::class::{
@@ -30,9 +30,9 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         );
     }
 
-    let named_fields = named_fields(class)?;
+    let struct_fields = struct_fields(class);
     let mut struct_cfg = parse_struct_attributes(class)?;
-    let mut fields = parse_fields(named_fields, struct_cfg.init_strategy)?;
+    let mut fields = parse_fields(struct_fields, struct_cfg.init_strategy)?;
     let is_editor_plugin = struct_cfg.is_editor_plugin();
 
     let mut deprecations = std::mem::take(&mut struct_cfg.deprecations);
@@ -43,8 +43,8 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let class_name = &class.name;
     let class_name_str: String = struct_cfg
         .rename
-        .map_or_else(|| class.name.clone(), |rename| rename)
-        .to_string();
+        .take()
+        .unwrap_or_else(|| class.name.to_string());
 
     // Determine if we can use ASCII for the class name (in most cases).
     let class_name_allocation = if class_name_str.is_ascii() {
@@ -57,7 +57,9 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let class_name_obj = util::class_name_obj(class_name);
 
     let is_internal = struct_cfg.is_internal;
+    let is_abstract = struct_cfg.is_abstract;
     let base_ty = &struct_cfg.base_ty;
+
     #[cfg(all(feature = "register-docs", since_api = "4.3"))]
     let docs = crate::docs::make_definition_docs(
         base_ty.to_string(),
@@ -73,7 +75,9 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let prv = quote! { ::godot::private };
     let godot_exports_impl = make_property_impl(class_name, &fields);
 
-    let godot_withbase_impl = if let Some(Field { name, ty, .. }) = &fields.base_field {
+    let godot_withbase_impl = if let Some(base_field) = &fields.base_field {
+        let member = base_field.member();
+        let ty = &base_field.ty;
         // Apply the span of the field's type so that errors show up on the field's type.
         quote_spanned! { ty.span()=>
             impl ::godot::obj::WithBaseField for #class_name {
@@ -85,7 +89,9 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
                 }
 
                 fn base_field(&self) -> &::godot::obj::Base<<#class_name as ::godot::obj::GodotClass>::Base> {
-                    &self.#name
+                    // Reports a `Base<T>` that doesn't match #[class(base = ...)] at the field, however `T` is spelled. Same check as in
+                    // the generated init, so that rustc reports a mismatch only once.
+                    <#ty as ::godot::private::IsBaseFieldOf<#base_class>>::as_base(&self.#member)
                 }
             }
         }
@@ -104,7 +110,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
 
     match struct_cfg.init_strategy {
         InitStrategy::Generated => {
-            godot_init_impl = make_godot_init_impl(class_name, &fields);
+            godot_init_impl = make_godot_init_impl(class_name, base_ty, &fields);
             create_fn = quote! { Some(#prv::callbacks::create::<#class_name>) };
 
             if cfg!(since_api = "4.2") {
@@ -134,11 +140,18 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     };
 
     let is_tool = struct_cfg.is_tool;
+    let init_level_const = struct_cfg.init_level.as_ref().map(|level| {
+        quote! {
+            const INIT_LEVEL: ::godot::init::InitLevel = ::godot::init::InitLevel::#level;
+        }
+    });
 
     Ok(quote! {
         impl ::godot::obj::GodotClass for #class_name {
             type Base = #base_class;
 
+            #init_level_const
+
             // Code duplicated in godot-codegen.
             fn class_name() -> ::godot::meta::ClassName {
                 use ::godot::meta::ClassName;
@@ -170,6 +183,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
             class_name: #class_name_obj,
             item: #prv::PluginItem::Struct {
                 base_class_name: #base_class_name_obj,
+                rust_type_name: ::std::any::type_name::<#class_name>(),
                 generated_create_fn: #create_fn,
                 generated_recreate_fn: #recreate_fn,
                 register_properties_fn: #prv::ErasedRegisterFn {
@@ -181,6 +195,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
                 is_editor_plugin: #is_editor_plugin,
                 is_internal: #is_internal,
                 is_instantiable: #is_instantiable,
+                is_abstract: #is_abstract,
                 #docs
             },
             init_level: {
@@ -228,7 +243,9 @@ struct ClassAttributes {
     init_strategy: InitStrategy,
     is_tool: bool,
     is_internal: bool,
-    rename: Option<Ident>,
+    is_abstract: bool,
+    init_level: Option<Ident>,
+    rename: Option<String>,
     deprecations: Vec<TokenStream>,
 }
 
@@ -238,15 +255,19 @@ impl ClassAttributes {
     }
 }
 
-fn make_godot_init_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
-    let base_init = if let Some(Field { name, ty, .. }) = &fields.base_field {
-        quote_spanned! { ty.span()=> #name: base, }
+fn make_godot_init_impl(class_name: &Ident, base_ty: &Ident, fields: &Fields) -> TokenStream {
+    let base_init = if let Some(base_field) = &fields.base_field {
+        let member = base_field.member();
+        let ty = &base_field.ty;
+        quote_spanned! { ty.span()=>
+            #member: <#ty as ::godot::private::IsBaseFieldOf<::godot::classes::#base_ty>>::from_base(base),
+        }
     } else {
         TokenStream::new()
     };
 
     let rest_init = fields.all_fields.iter().map(|field| {
-        let field_name = field.name.clone();
+        let member = field.member();
         let value_expr = field
             .default_val
             .clone()
@@ -254,7 +275,7 @@ fn make_godot_init_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             // Use quote_spanned with the field's span so that errors show up on the field and not the derive macro.
             .unwrap_or_else(|| quote_spanned! { field.span=> ::std::default::Default::default() });
 
-        quote! { #field_name: #value_expr, }
+        quote! { #member: #value_expr, }
     });
 
     quote! {
@@ -285,7 +306,7 @@ fn make_user_class_impl(
             .iter()
             .filter(|&field| field.is_onready)
             .map(|field| {
-                let field = &field.name;
+                let field = field.member();
                 quote! {
                     ::godot::private::auto_init(&mut self.#field, &base);
                 }
@@ -352,14 +373,18 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
     let mut init_strategy = InitStrategy::UserDefined;
     let mut is_tool = false;
     let mut is_internal = false;
-    let mut rename: Option<Ident> = None;
+    let mut is_abstract = false;
+    let mut init_level: Option<Ident> = None;
+    let mut rename: Option<String> = None;
     let mut deprecations = vec![];
 
     // #[class] attribute on struct
     if let Some(mut parser) = KvParser::parse(&class.attributes, "class")? {
         // #[class(base = Base)]
+        let mut explicit_base = false;
         if let Some(base) = parser.handle_ident("base")? {
             base_ty = base;
+            explicit_base = true;
         }
 
         // #[class(init)], #[class(no_init)]
@@ -374,15 +399,34 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             is_tool = true;
         }
 
-        // Deprecated #[class(editor_plugin)]
-        if let Some(_attr_key) = parser.handle_alone_with_span("editor_plugin")? {
-            deprecations.push(quote_spanned! { _attr_key.span()=>
-                ::godot::__deprecated::emit_deprecated_warning!(class_editor_plugin);
-            });
+        // #[class(editor_plugin)]
+        if let Some(attr_key) = parser.handle_alone_with_span("editor_plugin")? {
+            if explicit_base && base_ty != "EditorPlugin" {
+                return bail!(
+                    attr_key,
+                    "#[class(editor_plugin)] implies base = EditorPlugin, but base = {} was specified",
+                    base_ty
+                );
+            }
+
+            base_ty = ident("EditorPlugin");
+            is_tool = true;
+        }
+
+        // #[class(init_level = Servers)]
+        if let Some(level) = parser.handle_ident("init_level")? {
+            if !["Core", "Servers", "Scene", "Editor"].contains(&level.to_string().as_str()) {
+                return bail!(
+                    level,
+                    "#[class(init_level)] must be one of `Core`, `Servers`, `Scene` or `Editor`"
+                );
+            }
+
+            init_level = Some(level);
         }
 
-        // #[class(rename = NewName)]
-        rename = parser.handle_ident("rename")?;
+        // #[class(rename = NewName)], #[class(rename = "NewName")]
+        rename = parse_class_rename(&mut parser)?;
 
         // #[class(internal)]
         // Named "internal" following Godot terminology: https://github.com/godotengine/godot-cpp/blob/master/include/godot_cpp/core/class_db.hpp#L327
@@ -391,6 +435,18 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             is_internal = true;
         }
 
+        // #[class(abstract)]
+        if let Some(span) = parser.handle_alone_with_span("abstract")? {
+            if matches!(init_strategy, InitStrategy::Absent) {
+                return bail!(
+                    span,
+                    "#[class(abstract)] cannot be combined with #[class(no_init)], as subclasses need a constructor"
+                );
+            }
+
+            is_abstract = true;
+        }
+
         // Deprecated #[class(hidden)]
         if let Some(ident) = parser.handle_alone_with_span("hidden")? {
             require_api_version!("4.2", &ident, "#[class(hidden)]")?;
@@ -411,33 +467,103 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
         init_strategy,
         is_tool,
         is_internal,
+        is_abstract,
+        init_level,
         rename,
         deprecations,
     })
 }
 
-/// Fetches data for all named fields for a struct.
+/// Parses the `rename` key of `#[class]`, which accepts an identifier or a string literal.
+///
+/// Validates that the name can be used as a class name in GDScript.
+pub(crate) fn parse_class_rename(parser: &mut KvParser) -> ParseResult<Option<String>> {
+    let Some((key, value)) = parser.handle_any_entry("rename") else {
+        return Ok(None);
+    };
+
+    let Some(value) = value else {
+        return bail!(
+            key,
+            "expected `rename` to be followed by `= NewName` or `= \"NewName\"`"
+        );
+    };
+
+    let token = value.single()?;
+    let name = match &token {
+        TokenTree::Ident(ident) => ident.to_string(),
+        TokenTree::Literal(literal) => match util::parse_string_literal(literal) {
+            Some(name) => name,
+            None => return bail!(literal, "#[class(rename)] expects an identifier or string"),
+        },
+        other => return bail!(other, "#[class(rename)] expects an identifier or string"),
+    };
+
+    if let Err(reason) = validate_gdscript_identifier(&name) {
+        return bail!(token, "#[class(rename = {name:?})]: {reason}");
+    }
+
+    Ok(Some(name))
+}
+
+/// Checks that `name` is a valid identifier in GDScript, and not one of its keywords.
+fn validate_gdscript_identifier(name: &str) -> Result<(), String> {
+    // See https://docs.godotengine.org/en/stable/tutorials/scripting/gdscript/gdscript_basics.html#keywords.
+    const GDSCRIPT_KEYWORDS: &str = "and as assert await break breakpoint class class_name const continue elif else enum extends \
+        false for func if in INF is match namespace NAN not null or pass PI preload return self signal static super TAU trait true \
+        var void when while yield";
+
+    let mut chars = name.chars();
+    let Some(first) = chars.next() else {
+        return Err("class name must not be empty".to_string());
+    };
+
+    if !(first.is_alphabetic() || first == '_') {
+        return Err("class name must start with a letter or underscore".to_string());
+    }
+
+    if let Some(invalid) = chars.find(|&c| !(c.is_alphanumeric() || c == '_')) {
+        return Err(format!("class name must not contain {invalid:?}"));
+    }
+
+    if GDSCRIPT_KEYWORDS
+        .split_whitespace()
+        .any(|keyword| keyword == name)
+    {
+        return Err(format!("`{name}` is a reserved keyword in GDScript"));
+    }
+
+    Ok(())
+}
+
+/// Fetches data for all fields of a struct, together with their attributes.
 ///
-/// Errors if `class` is a tuple struct.
-fn named_fields(class: &venial::Struct) -> ParseResult<Vec<(venial::NamedField, Punct)>> {
-    // This is separate from parse_fields to improve compile errors. The errors from here demand larger and more non-local changes from the API
-    // user than those from parse_struct_attributes, so this must be run first.
+/// Unit structs have no fields; tuple struct fields are identified by their position.
+fn struct_fields(class: &venial::Struct) -> Vec<(Field, Vec<venial::Attribute>)> {
     match &class.fields {
         // TODO disallow unit structs in the future
         // It often happens that over time, a registered class starts to require a base field.
         // Extending a {} struct requires breaking less code, so we should encourage it from the start.
-        venial::Fields::Unit => Ok(vec![]),
-        venial::Fields::Tuple(_) => bail!(
-            &class.fields,
-            "#[derive(GodotClass)] is not supported for tuple structs",
-        )?,
-        venial::Fields::Named(fields) => Ok(fields.fields.inner.clone()),
+        venial::Fields::Unit => vec![],
+        venial::Fields::Tuple(fields) => fields
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, (field, _punct))| {
+                (Field::new_tuple(index, field), field.attributes.clone())
+            })
+            .collect(),
+        venial::Fields::Named(fields) => fields
+            .fields
+            .iter()
+            .map(|(field, _punct)| (Field::new(field), field.attributes.clone()))
+            .collect(),
     }
 }
 
 /// Returns field names and 1 base field, if available.
 fn parse_fields(
-    named_fields: Vec<(venial::NamedField, Punct)>,
+    struct_fields: Vec<(Field, Vec<venial::Attribute>)>,
     init_strategy: InitStrategy,
 ) -> ParseResult<Fields> {
     let mut all_fields = vec![];
@@ -446,9 +572,8 @@ fn parse_fields(
     let mut errors = vec![];
 
     // Attributes on struct fields
-    for (named_field, _punct) in named_fields {
+    for (mut field, attributes) in struct_fields {
         let mut is_base = false;
-        let mut field = Field::new(&named_field);
 
         // Base<T> type inference
         if path_ends_with_complex(&field.ty, "Base") {
@@ -461,7 +586,7 @@ fn parse_fields(
         }
 
         // #[init]
-        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "init")? {
+        if let Some(mut parser) = KvParser::parse(&attributes, "init")? {
             // #[init] on fields is useless if there is no generated constructor.
             if !matches!(init_strategy, InitStrategy::Generated) {
                 return bail!(
@@ -536,21 +661,31 @@ fn parse_fields(
         }
 
         // #[export]
-        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export")? {
+        if let Some(mut parser) = KvParser::parse(&attributes, "export")? {
             let export = FieldExport::new_from_kv(&mut parser)?;
             field.export = Some(export);
             parser.finish()?;
         }
 
         // #[var]
-        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "var")? {
+        if let Some(mut parser) = KvParser::parse(&attributes, "var")? {
             let var = FieldVar::new_from_kv(&mut parser)?;
+
+            if let Some(no_save) = field.export.as_ref().and_then(|e| e.no_save.as_ref()) {
+                if !var.usage_flags.is_inferred() {
+                    return bail!(
+                        no_save,
+                        "#[export(no_save)] cannot be combined with #[var(usage_flags)] or #[var(save)]"
+                    );
+                }
+            }
+
             field.var = Some(var);
             parser.finish()?;
         }
 
         // #[hint] to override type inference (must be at the end).
-        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "hint")? {
+        if let Some(mut parser) = KvParser::parse(&attributes, "hint")? {
             if let Some(override_base) = handle_opposite_keys(&mut parser, "base", "hint")? {
                 is_base = override_base;
             }
@@ -561,6 +696,23 @@ fn parse_fields(
             parser.finish()?;
         }
 
+        // Properties are registered by field name, which tuple fields don't have.
+        if field.tuple_index.is_some() {
+            if let Some(var) = field.var.as_ref() {
+                errors.push(error!(
+                    var.span,
+                    "#[var] requires a named field; tuple struct fields are not supported"
+                ));
+            }
+
+            if let Some(export) = field.export.as_ref() {
+                errors.push(error!(
+                    export.span,
+                    "#[export] requires a named field; tuple struct fields are not supported"
+                ));
+            }
+        }
+
         // Extra validation; eventually assign to base_fields or all_fields.
         if is_base {
             if field.is_onready {
@@ -591,11 +743,11 @@ fn parse_fields(
                 ));
             }
 
+            let span = field.span;
             if let Some(prev_base) = base_field.replace(field) {
                 // Ensure at most one Base<T>.
                 errors.push(error!(
-                    // base_field.unwrap().name,
-                    named_field,
+                    span,
                     "at most 1 field can have type Base<T>; previous is `{}`", prev_base.name
                 ));
             }
@@ -637,6 +789,14 @@ fn post_validate(base_ty: &Ident, is_tool: bool) -> ParseResult<()> {
 
     let class_name = base_ty.to_string();
 
+    if let Some(suggestion) = misspelled_base_class(&class_name) {
+        return bail!(
+            base_ty,
+            "Unknown base class `{class_name}`.\n\
+            help: did you mean `{suggestion}`? Class names are case-sensitive."
+        );
+    }
+
     let is_class_extension = is_class_virtual_extension(&class_name);
     let is_class_editor = is_class_editor_only(&class_name);
 
@@ -657,6 +817,58 @@ fn post_validate(base_ty: &Ident, is_tool: bool) -> ParseResult<()> {
     Ok(())
 }
 
+/// If `godot_class_name` is a misspelling of a commonly used base class, returns the correct name.
+///
+/// Only differences in case and underscores are detected, since no two engine classes differ in those alone. Names that are not close to
+/// any class in the list are left to the type checker.
+// Could use the full class list from extension_api.json, which is currently not available to the proc-macro crate.
+fn misspelled_base_class(godot_class_name: &str) -> Option<&'static str> {
+    const COMMON_BASE_CLASSES: &[&str] = &[
+        "AnimationPlayer",
+        "Area2D",
+        "Area3D",
+        "AudioStreamPlayer",
+        "Button",
+        "Camera2D",
+        "Camera3D",
+        "CanvasItem",
+        "CanvasLayer",
+        "CharacterBody2D",
+        "CharacterBody3D",
+        "CollisionShape2D",
+        "CollisionShape3D",
+        "Container",
+        "Control",
+        "EditorPlugin",
+        "Label",
+        "MeshInstance3D",
+        "Node",
+        "Node2D",
+        "Node3D",
+        "Object",
+        "Panel",
+        "RefCounted",
+        "Resource",
+        "RigidBody2D",
+        "RigidBody3D",
+        "SceneTree",
+        "Sprite2D",
+        "Sprite3D",
+        "StaticBody2D",
+        "StaticBody3D",
+        "TextureRect",
+        "Timer",
+    ];
+
+    let normalize = |name: &str| name.replace('_', "").to_ascii_lowercase();
+    let normalized = normalize(godot_class_name);
+
+    COMMON_BASE_CLASSES
+        .iter()
+        .find(|&&known| known != godot_class_name && normalize(known) == normalized)
+        .copied()
+}
+
 /// Whether a class exists primarily for GDExtension to overload virtual methods.
 // See post_validate(). Should be moved to godot-codegen > special_cases.rs.
 fn is_class_virtual_extension(godot_class_name: &str) -> bool {
@@ -0,0 +1,126 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+
+use crate::class::{derive_godot_class, parse_class_rename};
+use crate::util::{bail, path_is_single, KvParser};
+use crate::{util, ParseResult};
+
+/// `#[derive(Resource)]`: same as `#[derive(GodotClass)]`, with `base = Resource` and `init` as defaults.
+///
+/// The additional key `#[class(user_init)]` suppresses the default `init`, for classes that implement `IResource::init()` themselves.
+pub fn derive_resource(item: venial::Item) -> ParseResult<TokenStream> {
+    let mut class = item
+        .as_struct()
+        .ok_or_else(|| {
+            util::error_fn(
+                "#[derive(Resource)] is only allowed on structs",
+                item.name(),
+            )
+        })?
+        .clone();
+
+    // Inspect existing #[class] keys, without consuming them -- derive_godot_class() parses the attribute again.
+    let mut has_base = false;
+    let mut has_init = false;
+    let mut registered_name = class.name.to_string();
+
+    if let Some(mut parser) = KvParser::parse(&class.attributes, "class")? {
+        if let Some(base) = parser.handle_ident("base")? {
+            if base != "Resource" {
+                return bail!(
+                    base,
+                    "#[derive(Resource)] implies base = Resource, but base = {} was specified",
+                    base
+                );
+            }
+            has_base = true;
+        }
+
+        has_init = parser.handle_any("init").is_some()
+            || parser.handle_any("no_init").is_some()
+            || parser.handle_alone("user_init")?;
+
+        if let Some(rename) = parse_class_rename(&mut parser)? {
+            registered_name = rename;
+        }
+    }
+
+    let mut added_keys = vec![];
+    if !has_base {
+        added_keys.push(quote! { base = Resource });
+    }
+    if !has_init {
+        added_keys.push(quote! { init });
+    }
+
+    // Rebuild #[class(...)] with the added keys.
+    let class_attr_index = class
+        .attributes
+        .iter()
+        .position(|attr| path_is_single(&attr.path, "class"));
+
+    let existing_tokens: Vec<TokenTree> = match class_attr_index {
+        Some(index) => class.attributes.remove(index).get_value_tokens().to_vec(),
+        None => vec![],
+    };
+
+    // `user_init` is only understood by this derive; everything else is forwarded to #[derive(GodotClass)].
+    let existing_keys = existing_tokens
+        .split(|tt| matches!(tt, TokenTree::Punct(punct) if punct.as_char() == ','))
+        .filter(|key| !key.is_empty())
+        .filter(|key| !matches!(key, [TokenTree::Ident(ident)] if ident == "user_init"))
+        .map(|key| key.iter().cloned().collect::<TokenStream>());
+    let all_keys: Vec<TokenStream> = existing_keys.chain(added_keys).collect();
+
+    let new_attr = quote! {
+        #[class(#(#all_keys),*)]
+        struct __Resource;
+    };
+    let new_attr = venial::parse_item(new_attr)?.attributes_mut().remove(0);
+    class.attributes.push(new_attr);
+
+    let class_name = class.name.clone();
+    let default_path = format!("res://{}.tres", to_snake_case(&registered_name));
+    let doc = format!("Default path for saving `{registered_name}` resources: `{default_path}`.");
+
+    let godot_class_impl = derive_godot_class(venial::Item::Struct(class))?;
+
+    Ok(quote! {
+        #godot_class_impl
+
+        impl #class_name {
+            #[doc = #doc]
+            pub fn default_resource_path() -> ::godot::builtin::GString {
+                ::godot::builtin::GString::from(#default_path)
+            }
+        }
+    })
+}
+
+/// Converts `MyResourceName` to `my_resource_name`.
+fn to_snake_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 4);
+    let mut prev_lower_or_digit = false;
+
+    for c in name.chars() {
+        if c.is_uppercase() {
+            if prev_lower_or_digit {
+                result.push('_');
+            }
+            result.extend(c.to_lowercase());
+            prev_lower_or_digit = false;
+        } else {
+            result.push(c);
+            prev_lower_or_digit = c.is_lowercase() || c.is_ascii_digit();
+        }
+    }
+
+    result
+}
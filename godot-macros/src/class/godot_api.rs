@@ -5,21 +5,25 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::TokenStream;
+use proc_macro2::{TokenStream, TokenTree};
 
 use crate::class::{transform_inherent_impl, transform_trait_impl};
 use crate::util::{bail, venial_parse_meta, KvParser};
-use crate::ParseResult;
+use crate::{util, ParseResult};
 
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, ToTokens};
 
 fn parse_inherent_impl_attr(meta: TokenStream) -> Result<super::InherentImplAttr, venial::Error> {
     let item = venial_parse_meta(&meta, format_ident!("godot_api"), &quote! { fn func(); })?;
     let mut attr = KvParser::parse_required(item.attributes(), "godot_api", &meta)?;
     let secondary = attr.handle_alone("secondary")?;
+    let user_trait = attr.handle_expr("trait")?;
     attr.finish()?;
 
-    Ok(super::InherentImplAttr { secondary })
+    Ok(super::InherentImplAttr {
+        secondary,
+        user_trait,
+    })
 }
 
 pub fn attribute_godot_api(
@@ -45,20 +49,65 @@ pub fn attribute_godot_api(
         return bail!(decl, "invalid Self type for #[godot_api] impl");
     };
 
-    if decl.trait_ty.is_some() {
-        // 'meta' contains the parameters to the macro, that is, for `#[godot_api(a, b, x=y)]`, anything inside the braces.
-        // We currently don't accept any parameters for a trait `impl`, so show an error to the user if they added something there.
-        if meta.to_string() != "" {
+    // 'meta' contains the parameters to the macro, that is, for `#[godot_api(a, b, x=y)]`, anything inside the braces.
+    // Without parameters, a trait `impl` is a Godot interface (virtual methods), e.g. `impl INode for MyClass`.
+    if decl.trait_ty.is_some() && meta.is_empty() {
+        return transform_trait_impl(decl);
+    }
+
+    let attr = parse_inherent_impl_attr(meta.clone())?;
+
+    match (&decl.trait_ty, &attr.user_trait) {
+        (Some(trait_ty), Some(user_trait)) => validate_user_trait(trait_ty, user_trait)?,
+        (Some(_), None) => {
             return bail!(
                 meta,
-                "#[godot_api] on a trait implementation currently does not support any parameters"
+                "#[godot_api] on a trait implementation only supports `trait = ...` for user-defined traits"
             );
         }
-        transform_trait_impl(decl)
-    } else {
-        match parse_inherent_impl_attr(meta) {
-            Ok(meta) => transform_inherent_impl(meta, decl),
-            Err(err) => Err(err),
+        (None, Some(user_trait)) => {
+            return bail!(
+                user_trait,
+                "#[godot_api(trait = ...)] must be applied on a trait implementation `impl Trait for MyClass`"
+            );
         }
+        (None, None) => {}
     }
+
+    transform_inherent_impl(attr, decl)
+}
+
+/// Checks that the trait in `#[godot_api(trait = MyTrait)]` is the one being implemented.
+///
+/// Generic arguments are ignored, and either path may be more qualified than the other, e.g. `trait = Saveable` matches
+/// `impl crate::Saveable<i32> for MyClass`.
+fn validate_user_trait(trait_ty: &venial::TypeExpr, user_trait: &TokenStream) -> ParseResult<()> {
+    let idents = |path: TokenStream| -> Vec<String> {
+        util::strip_generic_args(path)
+            .into_iter()
+            .filter_map(|tt| match tt {
+                TokenTree::Ident(ident) => Some(ident.to_string()),
+                _ => None,
+            })
+            .collect()
+    };
+
+    let impl_path = idents(trait_ty.to_token_stream());
+    let attr_path = idents(user_trait.clone());
+
+    let (shorter, longer) = if impl_path.len() <= attr_path.len() {
+        (&impl_path, &attr_path)
+    } else {
+        (&attr_path, &impl_path)
+    };
+
+    if !shorter.is_empty() && longer.ends_with(shorter) {
+        return Ok(());
+    }
+
+    bail!(
+        user_trait,
+        "#[godot_api(trait = {user_trait})] does not match implemented trait `{}`",
+        trait_ty.to_token_stream()
+    )
 }
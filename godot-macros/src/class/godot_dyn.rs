@@ -11,6 +11,15 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 pub fn attribute_godot_dyn(input_decl: venial::Item) -> ParseResult<TokenStream> {
+    if let venial::Item::Trait(trait_decl) = &input_decl {
+        let trait_name = &trait_decl.name;
+        return bail!(
+            trait_decl,
+            "#[godot_dyn] is applied to `impl {trait_name} for MyClass` blocks, not to the trait definition\n\
+            help: trait objects are passed through Godot as `DynGd<Base, dyn {trait_name}>`, which needs no wrapper class",
+        );
+    }
+
     let venial::Item::Impl(decl) = input_decl else {
         return bail!(
             input_decl,
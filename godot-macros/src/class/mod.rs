@@ -6,6 +6,7 @@
  */
 
 mod derive_godot_class;
+mod derive_resource;
 mod godot_api;
 mod godot_dyn;
 mod data_models {
@@ -33,5 +34,6 @@ pub(crate) use data_models::property::*;
 pub(crate) use data_models::rpc::*;
 pub(crate) use data_models::signal::*;
 pub(crate) use derive_godot_class::*;
+pub(crate) use derive_resource::*;
 pub(crate) use godot_api::*;
 pub(crate) use godot_dyn::*;
@@ -40,12 +40,14 @@ use crate::util::{bail, ident, KvParser};
 /// - [Properties and exports](#properties-and-exports)
 ///    - [Property registration](#property-registration)
 ///    - [Property exports](#property-exports)
+///    - [Saving properties](#saving-properties)
 /// - [Signals](#signals)
 /// - [Further class customization](#further-class-customization)
 ///    - [Running code in the editor](#running-code-in-the-editor)
 ///    - [Editor plugins](#editor-plugins)
 ///    - [Class renaming](#class-renaming)
 ///    - [Class hiding](#class-hiding)
+///    - [Initialization level](#initialization-level)
 /// - [Further field customization](#further-field-customization)
 ///    - [Fine-grained inference hints](#fine-grained-inference-hints)
 ///
@@ -108,6 +110,19 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// To allow construction only through subclasses, use `#[class(abstract)]`. GDScript then rejects `MyStruct.new()`, but scripts
+/// extending `MyStruct` can be instantiated as usual. This corresponds to a "virtual" class in Godot, such as `Mesh`. Since subclasses
+/// rely on the constructor, `abstract` cannot be combined with `no_init`.
+///
+/// ```
+/// # use godot_macros::GodotClass;
+/// #[derive(GodotClass)]
+/// #[class(init, abstract)]
+/// struct MyStruct {
+///    // ...
+/// }
+/// ```
+///
 /// # Inheritance
 ///
 /// Unlike C++, Rust doesn't really have inheritance, but the GDExtension API lets us "inherit"
@@ -139,6 +154,34 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// The type parameter of `Base<T>` must match the base class declared in `#[class(base = ...)]` (or `RefCounted`, if omitted).
+/// A mismatch is reported at the field:
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node3D)]
+/// struct MyStruct {
+///     base: Base<Node>, // Error: class declares `Node3D` as its base class.
+/// }
+/// ```
+///
+/// The check is type-based, so the class may be spelled in any way, e.g. through an alias like `Base<N3>` after
+/// `use godot::classes::Node3D as N3;`.
+///
+/// For commonly used base classes, a name in `#[class(base = ...)]` that differs only in case or underscores is reported with a
+/// suggestion, e.g. `base = Node3d` with "did you mean `Node3D`?".
+///
+/// Tuple structs are supported as well; the base field is again recognized by its `Base<T>` type, wherever it appears.
+/// Since properties are registered by name, `#[var]` and `#[export]` require named fields.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D)]
+/// struct MyStruct(Base<Node2D>, #[init(val = 10)] i32);
+/// ```
+///
 ///
 /// # Properties and exports
 ///
@@ -283,6 +326,14 @@ use crate::util::{bail, ident, KvParser};
 ///     // @export_flags("A:1", "B:2", "AB:3")
 ///     #[export(flags = (A = 1, B = 2, AB = 3))]
 ///     flags: u32,
+///
+///     // @export_node_path("CharacterBody3D", "RigidBody3D")
+///     #[export(node_path_filter = (type = "CharacterBody3D, RigidBody3D"))]
+///     body: NodePath,
+///
+///     // @export_color_no_alpha -- also available as #[export(alpha_in_inspector = false)].
+///     #[export(color_no_alpha)]
+///     tint: Color,
 /// }
 ///
 /// ```
@@ -327,6 +378,30 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// ## Saving properties
+///
+/// `#[export]` properties are both shown in the editor and saved to disk (usage `DEFAULT`), while `#[var]` properties are neither.
+/// Two shorthands change what is stored:
+/// - `#[export(no_save)]` shows the property in the inspector, but does not save it (usage `EDITOR`).
+/// - `#[var(save)]` saves the property, but does not show it in the inspector (usage `STORAGE`).
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     // Editable, but reset every time the scene is loaded.
+///     #[export(no_save)]
+///     preview_color: Color,
+///
+///     // Persisted with the scene/resource, but not editable.
+///     #[var(save)]
+///     generated_seed: i64,
+/// }
+/// ```
+///
+/// Neither can be combined with an explicit `usage_flags` key.
+///
 /// # Signals
 ///
 /// The `#[signal]` attribute is quite limited at the moment. The functions it decorates (the signals) can accept parameters.
@@ -348,6 +423,27 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// Signal parameter types must be convertible to and from Godot, i.e. implement [`ToGodot`] and [`FromGodot`]. Other types are rejected
+/// at compile time, with the error pointing to the offending parameter:
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// struct NotGodotCompatible;
+///
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyClass {}
+///
+/// #[godot_api]
+/// impl MyClass {
+///     #[signal]
+///     fn on_hit(damage: NotGodotCompatible);
+/// }
+/// ```
+///
+/// [`ToGodot`]: ../meta/trait.ToGodot.html
+/// [`FromGodot`]: ../meta/trait.FromGodot.html
+///
 /// # Further class customization
 ///
 /// ## Running code in the editor
@@ -362,17 +458,35 @@ use crate::util::{bail, ident, KvParser};
 ///
 /// ## Editor plugins
 ///
-/// If you annotate a class with `#[class(editor_plugin)]`, it will be turned into an editor plugin. The
-/// class must then inherit from `EditorPlugin`, and an instance of that class will be automatically added
-/// to the editor when launched.
+/// Every class inheriting `EditorPlugin` is turned into an editor plugin: an instance of that class will be automatically added
+/// to the editor when launched. Editor plugins require `#[class(tool)]`, so that the code you write will actually run in the editor.
+///
+/// `#[class(editor_plugin)]` is a shorthand for `#[class(base = EditorPlugin, tool)]`:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::IEditorPlugin;
+/// #[derive(GodotClass)]
+/// #[class(init, editor_plugin)]
+/// struct MyPlugin {
+///     base: Base<EditorPlugin>,
+/// }
+///
+/// #[godot_api]
+/// impl IEditorPlugin for MyPlugin {
+///     fn enter_tree(&mut self) {
+///         // Set up menu items, docks, etc.
+///     }
+///
+///     fn exit_tree(&mut self) {
+///         // Clean up what was added in enter_tree().
+///     }
+/// }
+/// ```
 ///
 /// See [Godot's documentation of editor plugins](https://docs.godotengine.org/en/stable/tutorials/plugins/editor/index.html)
 /// for more information about editor plugins. But note that you do not need to create and enable the plugin
-/// through Godot's `Create New Plugin` menu for it to work, simply annotating the class with `editor_plugin`
-/// automatically enables it when the library is loaded.
-///
-/// This should usually be combined with `#[class(tool)]` so that the code you write will actually run in the
-/// editor.
+/// through Godot's `Create New Plugin` menu for it to work; the plugin is automatically enabled when the library is loaded.
 ///
 /// ## Class renaming
 ///
@@ -398,6 +512,9 @@ use crate::util::{bail, ident, KvParser};
 ///
 /// These classes will appear in the Godot editor and GDScript as "AnimalToad" or "NpcToad".
 ///
+/// The new name can also be given as a string, e.g. `#[class(rename = "AnimalToad")]`. Either way, it must be a valid GDScript
+/// identifier and not a GDScript keyword; this is checked at compile time.
+///
 /// ## Class hiding
 ///
 /// If you want to register a class with Godot, but not have it show up in the editor then you can use `#[class(internal)]`.
@@ -412,6 +529,24 @@ use crate::util::{bail, ident, KvParser};
 /// Even though this class is a `Node` and it has an init function, it still won't show up in the editor as a node you can add to a scene
 /// because we have added a `hidden` key to the class. This will also prevent it from showing up in documentation.
 ///
+/// ## Initialization level
+///
+/// Classes are registered with Godot during one of the [`InitLevel`](../init/enum.InitLevel.html)s `Core`, `Servers`, `Scene` and
+/// `Editor`. By default, a class uses the same level as its base class (e.g. `Scene` for `Node`, `Editor` for `EditorPlugin`).
+/// With `#[class(init_level = ...)]`, you can register it later -- for example at `Editor` level, so that the class is only available in
+/// editor builds of Godot, not in exported games.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(base=Node, init, tool, init_level=Editor)]
+/// pub struct LevelValidator {}
+/// ```
+///
+/// A class cannot be registered before its base class; this is checked during registration. To run custom code at a certain level,
+/// override [`ExtensionLibrary::on_level_init()`](../init/trait.ExtensionLibrary.html#method.on_level_init). Classes are only loaded at
+/// levels `>=` [`ExtensionLibrary::min_level()`](../init/trait.ExtensionLibrary.html#method.min_level).
+///
 /// # Further field customization
 ///
 /// ## Fine-grained inference hints
@@ -480,6 +615,7 @@ use crate::util::{bail, ident, KvParser};
     alias = "base",
     alias = "init",
     alias = "no_init",
+    alias = "abstract",
     alias = "var",
     alias = "export",
     alias = "tool",
@@ -490,6 +626,46 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     translate(input, class::derive_godot_class)
 }
 
+/// Derive macro for [`GodotClass`](../obj/trait.GodotClass.html) on `Resource` subclasses.
+///
+/// Shorthand for `#[derive(GodotClass)]` with `#[class(base = Resource, init)]`; use it _instead of_ `#[derive(GodotClass)]`.
+/// All other attributes work the same. Keys in an explicit `#[class]` attribute are kept, and `base`/`init` are only added if absent.
+/// A different `base` is rejected.
+///
+/// To implement [`IResource::init()`](../classes/trait.IResource.html#method.init) yourself, add `#[class(user_init)]`; `init` is then
+/// not added. This key is specific to `#[derive(Resource)]`.
+///
+/// In addition, the associated function `default_resource_path()` is generated, returning `res://<class_name>.tres` with the
+/// class name in snake case. It can serve as a default target for [`save()`](../tools/fn.save.html).
+///
+/// This derive is not part of the prelude, so that it doesn't clash with derives of the same name from other crates' preludes.
+/// Import it explicitly from `godot::register`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::register::Resource;
+///
+/// #[derive(Resource)]
+/// struct EnemyStats {
+///     #[export]
+///     health: i32,
+///
+///     #[export]
+///     speed: f32,
+/// }
+///
+/// // Equivalent to:
+/// // #[derive(GodotClass)]
+/// // #[class(base = Resource, init)]
+/// // struct EnemyStats { ... }
+///
+/// assert_eq!(EnemyStats::default_resource_path(), "res://enemy_stats.tres".into());
+/// ```
+#[proc_macro_derive(Resource, attributes(class, base, hint, var, export, init))]
+pub fn derive_resource(input: TokenStream) -> TokenStream {
+    translate(input, class::derive_resource)
+}
+
 /// Proc-macro attribute to be used with `impl` blocks of [`#[derive(GodotClass)]`][GodotClass] structs.
 ///
 /// Can be used in two ways:
@@ -651,6 +827,19 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Panics
+///
+/// By default, a panic inside a `#[func]` is caught at the FFI boundary. Its message is printed, and the call fails with a
+/// [`CallError`](../meta/error/struct.CallError.html) that callers like `Object::try_call()` can observe.
+///
+/// `#[func(no_panic_catch)]` removes this safety net, for example to get a native crash with a full backtrace in debug sessions.
+/// Since a panic cannot unwind through the C ABI, it then aborts the process (or, on Rust versions before 1.81, causes undefined
+/// behavior).
+///
+/// This also affects argument validation. Dynamic calls (varcall, e.g. untyped GDScript or `Object::call()`) still report invalid
+/// arguments as a regular call error. Typed calls (ptrcall, e.g. statically typed GDScript) convert arguments inside the uncaught
+/// region, so an argument that fails to convert -- for example `null` passed to a `Gd<T>` parameter -- aborts the process, too.
+///
 /// ## Virtual methods
 ///
 /// Functions with the `#[func(virtual)]` attribute are virtual functions, meaning attached scripts can override them.
@@ -797,6 +986,37 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///     pub fn two(&self) { }
 /// }
 /// ```
+///
+/// # User-defined traits
+///
+/// Methods of your own Rust traits can be registered, too. Annotate the `impl` block with `#[godot_api(trait = MyTrait)]`, where the
+/// trait must be the one being implemented. Only the `#[func]` methods are registered; other trait methods stay Rust-only.
+/// Signals and `#[func(virtual)]` are not supported in such blocks.
+///
+/// Such a block counts as an `impl` block just like inherent ones, so if the class has another `#[godot_api]` block, add `secondary`.
+/// ```no_run
+/// # use godot::prelude::*;
+/// trait Saveable {
+///     fn save(&self) -> Dictionary;
+///     fn version() -> i32;
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct MyPlayer {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api(trait = Saveable)]
+/// impl Saveable for MyPlayer {
+///     #[func] // Callable from GDScript as `save()`.
+///     fn save(&self) -> Dictionary {
+///         Dictionary::new()
+///     }
+///
+///     fn version() -> i32 { 1 } // Not registered.
+/// }
+/// ```
 #[doc(
     alias = "func",
     alias = "rpc",
@@ -804,7 +1024,8 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     alias = "signal",
     alias = "constant",
     alias = "rename",
-    alias = "secondary"
+    alias = "secondary",
+    alias = "trait"
 )]
 #[proc_macro_attribute]
 pub fn godot_api(meta: TokenStream, input: TokenStream) -> TokenStream {
@@ -821,6 +1042,9 @@ pub fn godot_api(meta: TokenStream, input: TokenStream) -> TokenStream {
 /// Establishing this relation allows godot-rust to upcast `MyGodotClass` to `dyn Trait` inside the library's
 /// [`DynGd`](../obj/struct.DynGd.html) smart pointer.
 ///
+/// `DynGd` is how trait objects cross the Godot boundary: it converts to and from `Variant`, can be stored in arrays, and can be used as
+/// `#[func]` parameter or return type. No wrapper class per trait is needed, so `#[godot_dyn]` is not applied to the trait definition itself.
+///
 /// # Code generation
 /// Given the following code,
 /// ```no_run
@@ -161,7 +161,6 @@ impl KvParser {
         Ok(Some(int))
     }
 
-    #[allow(dead_code)]
     pub fn handle_bool(&mut self, key: &str) -> ParseResult<Option<bool>> {
         let Some(expr) = self.handle_expr(key)? else {
             return Ok(None);
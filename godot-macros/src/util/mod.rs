@@ -27,6 +27,16 @@ pub fn c_str(string: &str) -> Literal {
     Literal::c_string(&c_string)
 }
 
+/// Returns the content of a plain string literal `"..."`, or `None` for other literals.
+///
+/// Escape sequences are not processed; this is intended for identifier-like content such as class names.
+pub fn parse_string_literal(literal: &Literal) -> Option<String> {
+    let repr = literal.to_string();
+    let content = repr.strip_prefix('"')?.strip_suffix('"')?;
+
+    Some(content.to_string())
+}
+
 pub fn class_name_obj(class: &impl ToTokens) -> TokenStream {
     let class = class.to_token_stream();
     quote! { <#class as ::godot::obj::GodotClass>::class_name() }
@@ -211,6 +221,40 @@ fn validate_self(original_impl: &venial::Impl, attr: &str) -> ParseResult<Ident>
     }
 }
 
+/// Removes generic arguments from a path, e.g. `a::Trait<T>` or `a::Trait::<T>` becomes `a::Trait`.
+pub(crate) fn strip_generic_args(path: TokenStream) -> TokenStream {
+    let mut result: Vec<TokenTree> = vec![];
+    let mut depth = 0usize;
+    let mut after_dash = false;
+
+    for tt in path {
+        let is_punct = |c: char| matches!(&tt, TokenTree::Punct(punct) if punct.as_char() == c);
+
+        if is_punct('<') {
+            if depth == 0 && result.len() >= 2 {
+                // Turbofish: drop the `::` before `<`.
+                let tail = &result[result.len() - 2..];
+                if tail
+                    .iter()
+                    .all(|tt| matches!(tt, TokenTree::Punct(punct) if punct.as_char() == ':'))
+                {
+                    result.truncate(result.len() - 2);
+                }
+            }
+            depth += 1;
+        } else if is_punct('>') && !after_dash {
+            // The `>` of `->` (e.g. in `Fn() -> T`) does not close a bracket.
+            depth = depth.saturating_sub(1);
+        } else if depth == 0 {
+            result.push(tt.clone());
+        }
+
+        after_dash = is_punct('-');
+    }
+
+    result.into_iter().collect()
+}
+
 /// Gets the right-most type name in the path.
 pub(crate) fn extract_typename(ty: &venial::TypeExpr) -> Option<venial::PathSegment> {
     match ty.as_path() {
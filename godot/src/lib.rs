@@ -183,8 +183,9 @@ pub mod init {
 
 /// Register/export Rust symbols to Godot: classes, methods, enums...
 pub mod register {
+    pub use godot_core::registry::class::class_name_to_rust_name;
     pub use godot_core::registry::property;
-    pub use godot_macros::{godot_api, godot_dyn, Export, GodotClass, GodotConvert, Var};
+    pub use godot_macros::{godot_api, godot_dyn, Export, GodotClass, GodotConvert, Resource, Var};
 
     #[cfg(feature = "__codegen-full")]
     pub use godot_core::registry::RpcConfig;
@@ -14,6 +14,24 @@ func test_missing_init():
 	assert_eq(can_instantiate, false, "ClassDB.can_instantiate() is false")
 	assert_eq(instance, null, "ClassDB.instantiate() returns null")
 
+class ConcreteSubclass extends AbstractBase:
+	func describe() -> String:
+		return "concrete %d" % base_value
+
+# AbstractBase.new() is already rejected when parsing the script, so instantiation is checked through ClassDB at runtime.
+func test_abstract_class_subclass():
+	var class_found = ClassDB.class_exists("AbstractBase")
+	var can_instantiate = ClassDB.can_instantiate("AbstractBase")
+	var instance = ClassDB.instantiate("AbstractBase")
+
+	assert_eq(class_found, true, "ClassDB.class_exists() is true")
+	assert_eq(can_instantiate, false, "ClassDB.can_instantiate() is false")
+	assert_eq(instance, null, "ClassDB.instantiate() returns null")
+
+	var obj = ConcreteSubclass.new()
+	assert_that(obj is AbstractBase, "subclass instance is AbstractBase")
+	assert_eq(obj.describe(), "concrete 7", "subclass sees fields initialized in Rust")
+
 func test_init_defaults():
 	var obj = WithInitDefaults.new()
 
@@ -52,3 +52,91 @@ func test_collision_object_2d_input_event():
 
 	window.queue_free()
 
+
+# Deferred calls are only flushed after the current frame, so the standard API cannot observe their effect.
+func test_add_child_deferred():
+	var tree: SceneTree = Engine.get_main_loop()
+	var parent := Node.new()
+	tree.root.add_child(parent)
+
+	var child := Node.new()
+	NodeFrameTest.add_child_deferred(parent, child)
+	assert_eq(parent.get_child_count(), 0, "child is not added immediately")
+
+	# process_frame is emitted before that frame's deferred calls are flushed, so wait for two of them.
+	await tree.process_frame
+	await tree.process_frame
+
+	assert_eq(parent.get_child_count(), 1, "child is added after the deferred calls are flushed")
+	assert_eq(child.get_parent(), parent, "child's parent is set")
+	parent.queue_free()
+
+func test_set_property_deferred():
+	var tree: SceneTree = Engine.get_main_loop()
+	var node := Node3D.new()
+
+	ObjectFrameTest.set_property_deferred(node, "visible", false)
+	assert_eq(node.visible, true, "property is not set immediately")
+
+	await tree.process_frame
+	await tree.process_frame
+
+	assert_eq(node.visible, false, "property is set after the deferred calls are flushed")
+	node.free()
+
+func test_connect_fn_deferred():
+	# Rust callables, and thus the receiver class, require Godot 4.2.
+	if not ClassDB.class_exists("DeferredSignalReceiver"):
+		return
+
+	var tree: SceneTree = Engine.get_main_loop()
+	var emitter := Node.new()
+	emitter.add_user_signal("test_signal")
+
+	var receiver = ClassDB.instantiate("DeferredSignalReceiver")
+	receiver.connect_to(emitter, "test_signal")
+
+	emitter.emit_signal("test_signal")
+	assert_eq(receiver.received_count(), 0, "closure is not invoked during emission")
+
+	await tree.process_frame
+	await tree.process_frame
+
+	assert_eq(receiver.received_count(), 1, "closure is invoked once the deferred calls are flushed")
+	emitter.free()
+
+# Within one frame, SceneTree emits physics_frame before updating physics timers, and process_frame before updating idle timers.
+# Between those two signals, only one kind of timer can advance.
+func test_scene_tree_timer_constructors():
+	var tree: SceneTree = Engine.get_main_loop()
+
+	await tree.physics_frame
+	var idle: SceneTreeTimer = SceneTreeFrameTest.idle_timer(tree, 10.0)
+	var physics: SceneTreeTimer = SceneTreeFrameTest.physics_timer(tree, 10.0)
+
+	await tree.process_frame
+	assert_eq(idle.time_left, 10.0, "idle timer does not advance during physics frames")
+	assert_that(physics.time_left < 10.0, "physics timer advances during physics frames")
+
+	var physics_left := physics.time_left
+	await tree.physics_frame
+	assert_that(idle.time_left < 10.0, "idle timer advances during process frames")
+	assert_eq(physics.time_left, physics_left, "physics timer does not advance during process frames")
+
+# SceneTree::next_frame() must resolve through a real frame, not just an emitted signal.
+func test_next_frame():
+	# Rust futures on signals require Godot 4.2.
+	if not ClassDB.class_exists("NextFrameProbe"):
+		return
+
+	var tree: SceneTree = Engine.get_main_loop()
+	var probe = ClassDB.instantiate("NextFrameProbe")
+	probe.start(tree)
+
+	assert_that(not probe.poll_ready(), "future is pending within the current frame")
+	assert_that(not probe.is_woken(), "waker is not called within the current frame")
+
+	await tree.process_frame
+
+	assert_that(probe.is_woken(), "waker is called once a frame has passed")
+	assert_that(probe.poll_ready(), "future resolves once a frame has passed")
@@ -476,6 +476,8 @@ fn generate_property_template(inputs: &[Input]) -> PropertyTests {
             export_global_dir: GString,
             #[export(multiline)]
             export_multiline: GString,
+            #[export(placeholder = "Enter name...")]
+            export_placeholder: GString,
             #[export(range = (0.0, 20.0))]
             export_range_float_0_20: f64,
             #[export(range = (-10.0, 20.0, 0.2))]
@@ -486,8 +488,8 @@ fn generate_property_template(inputs: &[Input]) -> PropertyTests {
             export_exp_easing: f64,
             #[export(color_no_alpha)]
             export_color_no_alpha: Color,
-            // Not implemented
-            //  #[export(node_path = ("Button", "TouchScreenButton"))] export_node_path_button_touch_screen_button: NodePath,
+            #[export(node_path_filter = (type = "Button, TouchScreenButton"))]
+            export_node_path_button_touch_screen_button: NodePath,
             #[export(flags = (Fire, Water, Earth, Wind))]
             export_flags_fire_water_earth_wind: i64,
             #[export(flags = (Self = 4, Allies = 8, Foes = 16))]
@@ -523,6 +525,7 @@ fn generate_property_template(inputs: &[Input]) -> PropertyTests {
 @export_dir var export_dir: String
 @export_global_dir var export_global_dir: String
 @export_multiline var export_multiline: String
+@export_placeholder("Enter name...") var export_placeholder: String
 @export_range(0, 20) var export_range_float_0_20: float
 @export_range(-10, 20, 0.2) var export_range_float_neg10_20_02: float
 @export_range(0, 100, 1, "or_greater", "or_less") var export_range_int_0_100_1_or_greater_or_less: int
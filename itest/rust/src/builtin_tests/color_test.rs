@@ -97,6 +97,41 @@ fn color_blend() {
     );
 }
 
+#[itest]
+fn color_blend_translucent() {
+    let red = Color::from_rgba(1.0, 0.0, 0.0, 1.0);
+    let translucent_green = Color::from_rgba(0.0, 1.0, 0.0, 0.5);
+    assert_eq_approx!(
+        red.blend(translucent_green),
+        Color::from_rgba(0.5, 0.5, 0.0, 1.0)
+    );
+
+    // Fully transparent colors blend to transparent black.
+    assert_eq_approx!(
+        Color::from_rgba(1.0, 1.0, 1.0, 0.0).blend(Color::from_rgba(0.0, 1.0, 0.0, 0.0)),
+        Color::from_rgba(0.0, 0.0, 0.0, 0.0)
+    );
+}
+
+#[itest]
+fn color_lerp() {
+    let from = Color::from_rgba(1.0, 0.0, 0.0, 1.0);
+    let to = Color::from_rgba(0.0, 0.0, 1.0, 0.0);
+
+    assert_eq_approx!(from.lerp(to, 0.0), from);
+    assert_eq_approx!(from.lerp(to, 1.0), to);
+    assert_eq_approx!(from.lerp(to, 0.25), Color::from_rgba(0.75, 0.0, 0.25, 0.75));
+}
+
+#[itest]
+fn color_darkened_lightened() {
+    let c = Color::from_rgba(1.0, 0.5, 0.0, 0.5);
+
+    // Alpha is not affected.
+    assert_eq_approx!(c.darkened(0.5), Color::from_rgba(0.5, 0.25, 0.0, 0.5));
+    assert_eq_approx!(c.lightened(0.5), Color::from_rgba(1.0, 0.75, 0.5, 0.5));
+}
+
 #[itest]
 fn color_to_u32() {
     let c = Color::from_html("#01020304").unwrap();
@@ -5,6 +5,8 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::error::Error;
+
 use godot::prelude::*;
 
 use crate::framework::{expect_panic, itest};
@@ -97,6 +99,104 @@ fn array_iter_shared() {
     assert_eq!(iter.next(), None);
 }
 
+#[itest]
+fn array_iter_typed() {
+    let array = varray![1, "two", 3];
+
+    let mut iter = array.iter_typed::<i64>();
+    assert_eq!(iter.next().map(Result::unwrap), Some(1));
+
+    let err = iter.next().unwrap().unwrap_err();
+    assert!(err.to_string().contains("index 1"), "{err}");
+    assert_eq!(err.value(), Some(&"two".to_variant()));
+
+    // Original conversion error is kept in the source chain.
+    let element_err = err.source().expect("element error");
+    let original = element_err.source().expect("original conversion error");
+    assert!(original.to_string().contains("STRING"), "{original}");
+
+    assert_eq!(iter.next().map(Result::unwrap), Some(3));
+    assert!(iter.next().is_none());
+
+    assert!(array.iter_all_typed::<i64>().is_err());
+
+    let array = varray![1, 2, 3];
+    let elements: Vec<i64> = array.iter_all_typed::<i64>().unwrap().collect();
+    assert_eq!(elements, vec![1, 2, 3]);
+}
+
+#[itest]
+fn array_windows() {
+    let array = array![1, 2, 3, 4];
+
+    let windows: Vec<Vec<i64>> = array.windows(3).collect();
+    assert_eq!(windows, vec![vec![1, 2, 3], vec![2, 3, 4]]);
+
+    assert_eq!(array.windows(4).count(), 1);
+    assert_eq!(array.windows(5).count(), 0);
+    assert_eq!(Array::<i64>::new().windows(1).count(), 0);
+
+    expect_panic("windows(0)", || {
+        let _ = array.windows(0);
+    });
+}
+
+#[itest]
+fn array_chunks() {
+    let array = array![1, 2, 3, 4, 5];
+
+    let chunks: Vec<Vec<i64>> = array.chunks(2).collect();
+    assert_eq!(chunks, vec![vec![1, 2], vec![3, 4], vec![5]]);
+
+    let chunks: Vec<Vec<i64>> = array.chunks(5).collect();
+    assert_eq!(chunks, vec![vec![1, 2, 3, 4, 5]]);
+    assert_eq!(Array::<i64>::new().chunks(3).count(), 0);
+
+    expect_panic("chunks(0)", || {
+        let _ = array.chunks(0);
+    });
+}
+
+#[itest]
+fn array_dedup() {
+    let array = array![1, 1, 2, 3, 3, 3, 1];
+    assert_eq!(array.dedup(), array![1, 2, 3, 1]);
+    assert_eq!(array.len(), 7, "dedup() leaves the original untouched");
+
+    let mut in_place = array.clone();
+    in_place.dedup_in_place();
+    assert_eq!(in_place, array![1, 2, 3, 1]);
+    assert_eq!(array, in_place, "arrays are shared");
+
+    assert_eq!(Array::<i64>::new().dedup(), Array::new());
+    assert_eq!(array![5].dedup(), array![5]);
+
+    // Godot's == applies for variant arrays, so 1 and 1.0 are duplicates.
+    let variants = varray![1, 1.0, "one", "one"];
+    assert_eq!(variants.dedup(), varray![1, "one"]);
+}
+
+#[itest]
+fn array_dedup_by_key() {
+    let array = array![10, 11, 20, 35, 31, 12];
+    assert_eq!(array.dedup_by_key(|n| n / 10), array![10, 20, 35, 12]);
+}
+
+#[itest]
+fn array_windows_typed() {
+    let array = varray![1, 2, "three", 4];
+
+    let mut windows = array.windows_typed::<i64>(2);
+    assert_eq!(windows.next().map(Result::unwrap), Some(vec![1, 2]));
+
+    let err = windows.next().unwrap().unwrap_err();
+    assert!(err.to_string().contains("index 2"), "{err}");
+    assert_eq!(err.value(), Some(&"three".to_variant()));
+    assert!(err.source().and_then(Error::source).is_some());
+    assert!(windows.next().unwrap().is_err());
+    assert!(windows.next().is_none());
+}
+
 #[itest]
 fn array_hash() {
     let array = array![1, 2];
@@ -439,6 +539,36 @@ fn typed_array_return_from_godot_func() {
     assert_eq!(children, array![&child]);
 }
 
+#[itest]
+fn typed_object_array() {
+    let mut array: Array<Gd<Node>> = Array::new();
+    let array_variant = array.to_variant();
+    assert_eq!(array_variant.call("is_typed", &[]), true.to_variant());
+    assert_eq!(
+        array_variant.call("get_typed_class_name", &[]),
+        StringName::from("Node").to_variant()
+    );
+
+    let first = Node::new_alloc();
+    let second = Node::new_alloc();
+    array.push(&first);
+    array.push(&second);
+
+    assert_eq!(array.get(0), Some(first.clone()));
+    assert_eq!(array.get(2), None);
+    assert_eq!(
+        array.iter_shared().collect::<Vec<_>>(),
+        vec![first.clone(), second.clone()]
+    );
+
+    assert_eq!(array.pop(), Some(second.clone()));
+    assert_eq!(array.pop(), Some(first.clone()));
+    assert_eq!(array.pop(), None);
+
+    first.free();
+    second.free();
+}
+
 #[itest]
 fn typed_array_try_from_untyped() {
     let node = Node::new_alloc();
@@ -14,6 +14,7 @@ use godot::init::GdextBuild;
 use godot::meta::ToGodot;
 use godot::obj::{Gd, NewAlloc, NewGd};
 use godot::register::{godot_api, GodotClass};
+use std::error::Error;
 use std::hash::Hasher;
 use std::sync::atomic::{AtomicU32, Ordering};
 
@@ -61,6 +62,8 @@ fn callable_validity() {
     assert!(!Callable::invalid().is_valid());
     assert!(Callable::invalid().is_null());
     assert!(!Callable::invalid().is_custom());
+    assert_eq!(obj.callable("assign_int").custom_info(), None);
+    assert_eq!(Callable::invalid().custom_info(), None);
     assert_eq!(Callable::invalid().object(), None);
     assert_eq!(Callable::invalid().object_id(), None);
     assert_eq!(Callable::invalid().method_name(), None);
@@ -187,6 +190,39 @@ fn callable_call_return() {
     assert_eq!(callable.callv(&varray!["string"]), Variant::nil());
 }
 
+#[itest]
+fn callable_try_call() {
+    let obj = CallableTestObj::new_gd();
+    let callable = obj.callable("stringify_int");
+
+    let result = callable.try_call(&[10.to_variant()]);
+    assert_eq!(
+        result.expect("valid call"),
+        10.to_variant().stringify().to_variant()
+    );
+
+    let err = callable
+        .try_call(&[])
+        .expect_err("call with too few arguments");
+    assert_eq!(err.class_name(), Some("Callable"));
+    assert_eq!(err.method_name(), "call");
+    assert_eq!(
+        err.to_string(),
+        "godot-rust function call failed: Callable::call()\
+        \n  Source: CallableTestObj::stringify_int()\
+        \n    Reason: function has 1 parameter, but received 0 arguments"
+    );
+
+    let err = callable
+        .try_call(&["string".to_variant()])
+        .expect_err("call with wrong argument type");
+    assert!(err.source().is_some());
+
+    Callable::invalid()
+        .try_call(&[1.to_variant()])
+        .expect_err("call on invalid callable");
+}
+
 #[cfg(since_api = "4.2")]
 #[itest]
 fn callable_call_engine() {
@@ -338,6 +374,10 @@ pub mod custom_callable {
         assert!(callable.is_custom());
         assert!(callable.object().is_none());
 
+        let info = callable.custom_info().expect("custom callable");
+        assert_eq!(info.name, "sum");
+        assert_eq!(info.object, None);
+
         let sum1 = callable.callv(&varray![1, 2, 4, 8]);
         assert_eq!(sum1, 15.to_variant());
 
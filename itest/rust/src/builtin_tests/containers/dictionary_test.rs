@@ -7,7 +7,8 @@
 
 use std::collections::{HashMap, HashSet};
 
-use godot::builtin::{dict, varray, Dictionary, Variant};
+use godot::builtin::{dict, varray, Dictionary, GString, GodotHashMap, Variant, VariantType};
+use godot::meta::error::DictError;
 use godot::meta::{FromGodot, ToGodot};
 use godot::sys::GdextBuild;
 
@@ -226,6 +227,109 @@ fn dictionary_at() {
     });
 }
 
+#[itest]
+fn dictionary_get_typed() {
+    let dictionary = dict! {
+        "foo": 0,
+        "baz": "foobar",
+        "nil": Variant::nil(),
+    };
+
+    assert_eq!(dictionary.try_get_typed::<_, i64>("foo").unwrap(), 0);
+    assert_eq!(
+        dictionary.try_get_typed::<_, GString>("baz").unwrap(),
+        GString::from("foobar")
+    );
+
+    let err = dictionary.try_get_typed::<_, i64>("missing").unwrap_err();
+    assert!(
+        matches!(&err, DictError::KeyNotFound { key } if *key == "missing".to_variant()),
+        "{err}"
+    );
+
+    let err = dictionary.try_get_typed::<_, i64>("baz").unwrap_err();
+    let DictError::TypeMismatch {
+        key,
+        expected,
+        actual,
+        source,
+    } = &err
+    else {
+        panic!("expected type mismatch, got: {err}");
+    };
+    assert_eq!(*key, "baz".to_variant());
+    assert_eq!(*expected, "i64");
+    assert_eq!(*actual, VariantType::STRING);
+    assert_eq!(source.value(), Some(&"foobar".to_variant()));
+
+    let err = dictionary.try_get_typed::<_, i64>("nil").unwrap_err();
+    assert!(matches!(err, DictError::TypeMismatch { .. }), "{err}");
+
+    assert_eq!(dictionary.at_typed::<_, i64>("foo"), 0);
+    expect_panic("at_typed() with missing key", || {
+        dictionary.at_typed::<_, i64>("missing");
+    });
+    expect_panic("at_typed() with wrong type", || {
+        dictionary.at_typed::<_, bool>("foo");
+    });
+}
+
+#[itest]
+fn godot_hash_map_basic() {
+    let mut map = GodotHashMap::<GString, i64>::new();
+    assert!(map.is_empty());
+
+    assert_eq!(map.insert("one".into(), 1), None);
+    assert_eq!(map.insert("two".into(), 2), None);
+    assert_eq!(map.insert("one".into(), 10), Some(1));
+    assert_eq!(map.len(), 2);
+
+    assert_eq!(map.get(&"one".into()), Some(10));
+    assert_eq!(map.get(&"three".into()), None);
+    assert!(map.contains_key(&"two".into()));
+
+    let entries: Vec<(GString, i64)> = map.iter().collect();
+    assert_eq!(entries, vec![("one".into(), 10), ("two".into(), 2)]);
+
+    assert_eq!(map.remove(&"two".into()), Some(2));
+    assert_eq!(map.remove(&"two".into()), None);
+    assert!(!map.contains_key(&"two".into()));
+
+    map.clear();
+    assert!(map.is_empty());
+}
+
+#[itest]
+fn godot_hash_map_convert() {
+    let map: GodotHashMap<i64, bool> = [(1, true), (2, false)].into_iter().collect();
+
+    let dictionary = map.to_godot();
+    assert_eq!(dictionary, dict! { 1: true, 2: false });
+
+    let back = GodotHashMap::<i64, bool>::from_godot(dictionary);
+    assert_eq!(back, map);
+
+    let variant = map.to_variant();
+    assert_eq!(variant.to::<GodotHashMap<i64, bool>>(), map);
+
+    let mixed = dict! { 1: true, "two": false };
+    GodotHashMap::<i64, bool>::try_from_godot(mixed.clone()).expect_err("key of wrong type");
+    GodotHashMap::<i64, i64>::try_from_godot(dict! { 1: true }).expect_err("value of wrong type");
+    GodotHashMap::<Variant, bool>::try_from_godot(mixed).expect("variant keys accept anything");
+}
+
+#[itest]
+fn godot_hash_map_shares_data() {
+    let mut map = GodotHashMap::<i64, i64>::new();
+    let alias = map.clone();
+    let copy = map.duplicate();
+
+    map.insert(1, 2);
+    assert_eq!(alias.get(&1), Some(2));
+    assert_eq!(copy.get(&1), None);
+    assert_eq!(map.as_dictionary().get(1), Some(2.to_variant()));
+}
+
 #[itest]
 fn dictionary_insert() {
     let mut dictionary = dict! {
@@ -562,6 +666,28 @@ fn dictionary_iter_simultaneous() {
     assert_eq!(nils, 2);
 }
 
+#[itest]
+fn dictionary_iter_try_typed() {
+    let dictionary = dict! {
+        "foo": 0,
+        "bar": 2,
+        "baz": "not an int",
+    };
+
+    let results: Vec<Result<(String, i64), _>> = dictionary.iter_shared().try_typed().collect();
+    assert_eq!(results.len(), 3, "iteration continues after an error");
+    assert_eq!(results.iter().filter(|result| result.is_err()).count(), 1);
+
+    // Skip pairs that fail to convert.
+    let valid: HashMap<String, i64> = dictionary.iter_shared().try_typed().flatten().collect();
+    assert_eq!(valid, HashMap::from([("foo".into(), 0), ("bar".into(), 2)]));
+
+    // Or propagate the first error.
+    let all: Result<HashMap<String, i64>, _> = dictionary.iter_shared().try_typed().collect();
+    let err = all.expect_err("\"baz\" is not an int");
+    assert_eq!(err.value(), Some(&"not an int".to_variant()));
+}
+
 #[itest]
 fn dictionary_iter_panics() {
     expect_panic(
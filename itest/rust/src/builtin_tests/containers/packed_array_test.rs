@@ -7,8 +7,9 @@
 
 use crate::framework::{expect_panic, itest};
 use godot::builtin::{
-    Color, GString, PackedByteArray, PackedColorArray, PackedFloat32Array, PackedInt32Array,
-    PackedStringArray,
+    ArrayStats, Color, GString, PackedByteArray, PackedColorArray, PackedFloat32Array,
+    PackedInt32Array, PackedStringArray, PackedVector2Array, PackedVector3Array, Transform2D,
+    Transform3D, Vector2, Vector3,
 };
 
 #[itest]
@@ -305,3 +306,166 @@ fn packed_array_format() {
     let a = PackedByteArray::new();
     assert_eq!(format!("{a}"), "[]");
 }
+
+#[itest]
+fn packed_array_map_to_packed() {
+    let array = PackedVector2Array::from(&[Vector2::new(1.0, 2.0), Vector2::new(-3.0, 4.0)]);
+    let mapped = array.map_to_packed(|v| v * 2.0);
+
+    assert_eq!(
+        mapped.to_vec(),
+        vec![Vector2::new(2.0, 4.0), Vector2::new(-6.0, 8.0)]
+    );
+    assert_eq!(array[0], Vector2::new(1.0, 2.0), "source is unchanged");
+
+    let empty = PackedVector3Array::new().map_to_packed(|v| v + Vector3::ONE);
+    assert!(empty.is_empty());
+}
+
+#[itest]
+fn packed_string_array_functional() {
+    let words: PackedStringArray = ["apple", "banana", "cherry"]
+        .into_iter()
+        .map(GString::from)
+        .collect();
+
+    assert_eq!(words.join(", "), GString::from("apple, banana, cherry"));
+    assert_eq!(words.join(""), GString::from("applebananacherry"));
+
+    let upper = words.map(|word| word.to_upper());
+    assert_eq!(upper.join("/"), GString::from("APPLE/BANANA/CHERRY"));
+    assert_eq!(words[0], GString::from("apple"), "source is unchanged");
+
+    let long = words.filter(|word| word.len() > 5);
+    assert_eq!(long.join(" "), GString::from("banana cherry"));
+
+    assert!(words.any(|word| word.begins_with("ch")));
+    assert!(!words.any(|word| word.is_empty()));
+    assert!(words.all(|word| word.len() >= 5));
+    assert!(!words.all(|word| word.contains("an")));
+
+    let empty = PackedStringArray::new();
+    assert_eq!(empty.join(", "), GString::new());
+    assert!(empty.map(|word| word.clone()).is_empty());
+    assert!(empty.filter(|_| true).is_empty());
+    assert!(!empty.any(|_| true));
+    assert!(empty.all(|_| false));
+}
+
+#[itest]
+fn packed_array_transform() {
+    let transform = Transform2D::from_angle_origin(0.0, Vector2::new(10.0, 20.0));
+    let array = PackedVector2Array::from(&[Vector2::ZERO, Vector2::new(1.0, 1.0)]);
+
+    assert_eq!(
+        array.transform(&transform).to_vec(),
+        vec![Vector2::new(10.0, 20.0), Vector2::new(11.0, 21.0)]
+    );
+
+    let transform = Transform3D::IDENTITY.scaled(Vector3::new(2.0, 3.0, 4.0));
+    let array = PackedVector3Array::from(&[Vector3::ONE, Vector3::new(1.0, -1.0, 0.5)]);
+
+    assert_eq!(
+        array.transform(&transform).to_vec(),
+        vec![Vector3::new(2.0, 3.0, 4.0), Vector3::new(2.0, -3.0, 2.0)]
+    );
+}
+
+#[itest]
+fn packed_array_statistics() {
+    let floats = PackedFloat32Array::from(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+    assert_eq!(
+        floats.statistics(),
+        Some(ArrayStats {
+            min: 2.0,
+            max: 9.0,
+            mean: 5.0,
+            variance: 4.0,
+            len: 8,
+        })
+    );
+
+    let ints = PackedInt32Array::from(&[i32::MAX, i32::MAX, -3]);
+    let stats = ints.statistics().expect("non-empty array");
+    assert_eq!(stats.min, -3);
+    assert_eq!(stats.max, i32::MAX);
+    assert_eq!(stats.len, 3);
+    assert!(stats.mean > 1e9, "no overflow in mean");
+
+    let single = PackedInt32Array::from(&[7]).statistics();
+    assert_eq!(single.map(|s| (s.mean, s.variance)), Some((7.0, 0.0)));
+
+    assert_eq!(PackedFloat32Array::new().statistics(), None);
+    assert_eq!(PackedInt32Array::new().statistics(), None);
+}
+
+#[itest]
+fn packed_array_from_range() {
+    assert_eq!(
+        PackedInt32Array::from_range(0..5),
+        PackedInt32Array::from(&[0, 1, 2, 3, 4])
+    );
+    assert_eq!(
+        PackedInt32Array::from_range(-2..=1),
+        PackedInt32Array::from(&[-2, -1, 0, 1])
+    );
+    assert_eq!(
+        PackedInt32Array::from_range(..3),
+        PackedInt32Array::from(&[0, 1, 2])
+    );
+    assert_eq!(
+        PackedInt32Array::from_range(i32::MAX - 1..=i32::MAX),
+        PackedInt32Array::from(&[i32::MAX - 1, i32::MAX])
+    );
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = PackedInt32Array::from_range(5..0);
+    assert!(reversed.is_empty());
+    assert!(PackedInt32Array::from_range(3..3).is_empty());
+}
+
+#[itest]
+fn packed_array_from_linspace() {
+    assert_eq!(
+        PackedFloat32Array::from_linspace(0.0, 1.0, 5),
+        PackedFloat32Array::from(&[0.0, 0.25, 0.5, 0.75, 1.0])
+    );
+    assert_eq!(
+        PackedFloat32Array::from_linspace(2.0, -2.0, 3),
+        PackedFloat32Array::from(&[2.0, 0.0, -2.0])
+    );
+    assert_eq!(
+        PackedFloat32Array::from_linspace(3.0, 7.0, 1),
+        PackedFloat32Array::from(&[3.0])
+    );
+    assert!(PackedFloat32Array::from_linspace(0.0, 1.0, 0).is_empty());
+
+    // Last element is exact, even if the step is not representable.
+    let thirds = PackedFloat32Array::from_linspace(0.0, 0.1, 4);
+    assert_eq!(thirds.as_slice().last(), Some(&0.1));
+}
+
+#[itest]
+fn packed_array_from_fn() {
+    let squares = PackedFloat32Array::from_fn(4, |i| (i * i) as f32);
+    assert_eq!(squares, PackedFloat32Array::from(&[0.0, 1.0, 4.0, 9.0]));
+
+    assert!(PackedFloat32Array::from_fn(0, |_| unreachable!()).is_empty());
+}
+
+#[cfg(feature = "codegen-full")]
+#[itest]
+fn packed_byte_array_base64() {
+    let bytes = PackedByteArray::from(b"godot-rust".as_slice());
+    let encoded = bytes.to_base64();
+    assert_eq!(encoded, GString::from("Z29kb3QtcnVzdA=="));
+
+    let decoded = PackedByteArray::from_base64(&encoded);
+    assert_eq!(decoded, Some(bytes));
+
+    assert_eq!(
+        PackedByteArray::from_base64(""),
+        Some(PackedByteArray::new())
+    );
+    assert_eq!(PackedByteArray::from_base64("*not base64*"), None);
+}
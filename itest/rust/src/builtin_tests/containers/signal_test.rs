@@ -5,15 +5,16 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::{GString, Signal, StringName};
+use godot::builtin::{varray, Dictionary, GString, Signal, SignalConnection, StringName};
+use godot::classes::object::ConnectFlags;
 use godot::classes::{Object, RefCounted};
 use godot::meta::ToGodot;
-use godot::obj::{Base, Gd, NewAlloc, NewGd, WithBaseField};
+use godot::obj::{Base, EngineBitfield, Gd, NewAlloc, NewGd, WithBaseField};
 use godot::register::{godot_api, GodotClass};
 use godot::sys;
 use std::cell::Cell;
 
-use crate::framework::itest;
+use crate::framework::{expect_panic, itest};
 
 #[itest]
 fn signal_basic_connect_emit() {
@@ -60,6 +61,65 @@ fn signal_construction_and_id() {
     assert_eq!(signal.object(), None);
 }
 
+#[itest]
+fn signal_typed_connections() {
+    let mut object = RefCounted::new_gd();
+    object.add_user_signal("test_signal");
+
+    let signal = Signal::from_object_signal(&object, "test_signal");
+    assert_eq!(signal.connection_count(), 0);
+    assert!(signal.typed_connections().is_empty());
+
+    let receiver = Receiver::new_alloc();
+    let plain = receiver.callable("receiver_0");
+    let bound = receiver.callable("receiver_1").bindv(&varray![987]);
+
+    signal.connect(&plain, 0);
+    signal.connect(&bound, ConnectFlags::DEFERRED.ord() as i64);
+    assert_eq!(signal.connection_count(), 2);
+
+    let connections = signal.typed_connections();
+    let plain_conn = connections
+        .iter()
+        .find(|conn| conn.binds.is_empty())
+        .expect("plain connection");
+    let bound_conn = connections
+        .iter()
+        .find(|conn| !conn.binds.is_empty())
+        .expect("bound connection");
+
+    assert_eq!(plain_conn.callable, plain);
+    assert_eq!(plain_conn.flags.ord(), 0);
+
+    assert!(signal.is_connected(&bound_conn.callable));
+    assert_eq!(bound_conn.flags, ConnectFlags::DEFERRED);
+    assert_eq!(bound_conn.binds, vec![987.to_variant()]);
+
+    receiver.free();
+}
+
+#[itest]
+fn signal_connection_from_dictionary() {
+    let mut object = RefCounted::new_gd();
+    object.add_user_signal("test_signal");
+
+    let receiver = Receiver::new_alloc();
+    let callable = receiver.callable("receiver_0");
+    object.connect("test_signal", &callable);
+
+    let dict = object.get_signal_connection_list("test_signal").at(0);
+    let connection = SignalConnection::from_dictionary(&dict);
+    assert_eq!(connection.callable, callable);
+    assert_eq!(connection.flags.ord(), 0);
+    assert!(connection.binds.is_empty());
+
+    expect_panic("dictionary without connection entries", || {
+        SignalConnection::from_dictionary(&Dictionary::new());
+    });
+
+    receiver.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helper types
 
@@ -115,12 +175,16 @@ const SIGNAL_ARG_STRING: &str = "Signal string arg";
 
 #[cfg(since_api = "4.2")]
 mod custom_callable {
-    use godot::builtin::{Callable, Signal};
-    use godot::classes::Node;
+    use godot::builtin::{godot_select, Callable, Signal, StringName};
+    use godot::classes::{Node, Object, SceneTree, SceneTreeEvent, SceneTreeSignal};
     use godot::meta::ToGodot;
     use godot::obj::{Gd, NewAlloc};
-    use std::sync::atomic::{AtomicU32, Ordering};
-    use std::sync::Arc;
+    use godot::register::{godot_api, GodotClass};
+    use std::future::Future;
+    use std::pin::pin;
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Wake, Waker};
 
     use crate::builtin_tests::containers::callable_test::custom_callable::PanicCallable;
     use crate::framework::{itest, TestContext};
@@ -216,9 +280,259 @@ mod custom_callable {
         );
     }
 
+    #[itest]
+    fn signal_connect_fn() {
+        let mut node = Node::new_alloc();
+        node.add_user_signal("test_signal");
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let received_in_fn = received.clone();
+        let handle = node
+            .connect_fn("test_signal", move |args| {
+                received_in_fn.lock().unwrap().extend_from_slice(args);
+            })
+            .expect("connect_fn() succeeds");
+
+        assert_eq!(handle.signal_name(), &"test_signal".into());
+        assert!(handle.is_connected());
+
+        node.emit_signal("test_signal", &[12.to_variant(), "text".to_variant()]);
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![12.to_variant(), "text".to_variant()]
+        );
+
+        // After disconnecting, further emissions are not received.
+        assert!(handle.clone().disconnect());
+        assert!(!handle.is_connected());
+        assert!(!handle.disconnect());
+
+        node.emit_signal("test_signal", &[34.to_variant()]);
+        assert_eq!(received.lock().unwrap().len(), 2);
+
+        node.free();
+    }
+
+    #[itest]
+    fn signal_connect_fn_deferred_and_dead_object() {
+        let mut node = Node::new_alloc();
+        node.add_user_signal("test_signal");
+
+        let handle = node
+            .connect_fn_deferred("test_signal", |_args| {})
+            .expect("connect_fn_deferred() succeeds");
+        assert!(handle.is_connected());
+
+        // Handle does not keep the object alive; operations on a dead object are no-ops.
+        // Deferred invocation on emission is checked in `test_connect_fn_deferred` in `SpecialTests.gd`.
+        node.free();
+        assert!(!handle.is_connected());
+        assert!(!handle.disconnect());
+    }
+
+    // Used in `SpecialTests.gd`, which can let frames pass between calls.
+    #[derive(GodotClass)]
+    #[class(init, base=RefCounted)]
+    pub struct DeferredSignalReceiver {
+        received: Arc<AtomicU32>,
+    }
+
+    #[godot_api]
+    impl DeferredSignalReceiver {
+        #[func]
+        fn connect_to(&self, mut source: Gd<Object>, signal: StringName) {
+            let received = self.received.clone();
+            source
+                .connect_fn_deferred(&signal, move |_args| {
+                    received.fetch_add(1, Ordering::SeqCst);
+                })
+                .expect("connect_fn_deferred() succeeds");
+        }
+
+        #[func]
+        fn received_count(&self) -> u32 {
+            self.received.load(Ordering::SeqCst)
+        }
+    }
+
+    #[itest]
+    fn signal_await_once() {
+        let mut node = Node::new_alloc();
+        node.add_user_signal("test_signal");
+
+        let signal = Signal::from_object_signal(&node, "test_signal");
+        let mut future = pin!(signal.await_once());
+
+        let waker = Arc::new(FlagWaker::default());
+        let task_waker = Waker::from(waker.clone());
+        let mut cx = Context::from_waker(&task_waker);
+
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+        assert!(!waker.woken.load(Ordering::SeqCst));
+
+        node.emit_signal("test_signal", &[]);
+        assert!(waker.woken.load(Ordering::SeqCst));
+        assert!(future.as_mut().poll(&mut cx).is_ready());
+
+        // One-shot connection is removed after the first emission.
+        assert!(signal.connections().is_empty());
+
+        node.free();
+    }
+
+    #[itest]
+    fn signal_await_next_frame(ctx: &TestContext) {
+        let tree = ctx.scene_tree.get_tree().unwrap();
+        let mut future = pin!(tree.next_frame());
+
+        let waker = Arc::new(FlagWaker::default());
+        let task_waker = Waker::from(waker.clone());
+        let mut cx = Context::from_waker(&task_waker);
+
+        // Resolves only once the frame has been processed (checked in `test_next_frame` in `SpecialTests.gd`).
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+        assert!(!waker.woken.load(Ordering::SeqCst));
+    }
+
+    // Used in `SpecialTests.gd`, which can let frames pass between calls.
+    #[derive(GodotClass)]
+    #[class(init, base=RefCounted)]
+    pub struct NextFrameProbe {
+        future: Option<std::pin::Pin<Box<dyn Future<Output = ()>>>>,
+        waker: Arc<FlagWaker>,
+    }
+
+    #[godot_api]
+    impl NextFrameProbe {
+        #[func]
+        fn start(&mut self, tree: Gd<SceneTree>) {
+            self.future = Some(Box::pin(tree.next_frame()));
+        }
+
+        /// Polls the future once; returns whether it has resolved.
+        #[func]
+        fn poll_ready(&mut self) -> bool {
+            let task_waker = Waker::from(self.waker.clone());
+            let mut cx = Context::from_waker(&task_waker);
+
+            let future = self.future.as_mut().expect("start() called");
+            future.as_mut().poll(&mut cx).is_ready()
+        }
+
+        #[func]
+        fn is_woken(&self) -> bool {
+            self.waker.woken.load(Ordering::SeqCst)
+        }
+    }
+
+    #[itest]
+    fn signal_await_matching() {
+        let mut node = Node::new_alloc();
+        node.add_user_signal("test_signal");
+
+        let signal = Signal::from_object_signal(&node, "test_signal");
+        let mut future = pin!(signal.await_matching(|args| args[0].to::<i64>() > 10));
+
+        let task_waker = Waker::from(Arc::new(FlagWaker::default()));
+        let mut cx = Context::from_waker(&task_waker);
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+
+        // Rejected by filter: stays connected.
+        node.emit_signal("test_signal", &[5.to_variant()]);
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+        assert_eq!(signal.connections().len(), 1);
+
+        node.emit_signal("test_signal", &[42.to_variant()]);
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(args) => assert_eq!(args, vec![42.to_variant()]),
+            Poll::Pending => panic!("future should be resolved"),
+        }
+        assert!(signal.connections().is_empty());
+
+        node.free();
+    }
+
+    #[itest]
+    fn signal_godot_select() {
+        let mut node = Node::new_alloc();
+        node.add_user_signal("signal_a");
+        node.add_user_signal("signal_b");
+
+        let signal_a = Signal::from_object_signal(&node, "signal_a");
+        let signal_b = Signal::from_object_signal(&node, "signal_b");
+
+        let mut future = pin!(async {
+            godot_select! {
+                () = signal_a.await_once() => -1,
+                args = signal_b.await_matching(|_| true) => args[0].to::<i64>(),
+            }
+        });
+
+        let task_waker = Waker::from(Arc::new(FlagWaker::default()));
+        let mut cx = Context::from_waker(&task_waker);
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+
+        node.emit_signal("signal_b", &[7.to_variant()]);
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(7));
+
+        // The future that did not complete has been dropped and disconnected.
+        assert!(signal_a.connections().is_empty());
+        assert!(signal_b.connections().is_empty());
+
+        // Default arm runs if nothing is ready immediately.
+        let mut future = pin!(async {
+            godot_select! {
+                () = signal_a.await_once() => 1,
+                default => 0,
+            }
+        });
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(0));
+        assert!(signal_a.connections().is_empty());
+
+        node.free();
+    }
+
+    #[itest]
+    fn scene_tree_await_signal(ctx: &TestContext) {
+        let tree = ctx.scene_tree.get_tree().unwrap();
+        let mut future = pin!(tree.await_signal(SceneTreeSignal::NodeAdded {
+            name_pattern: Some("Enemy*".into()),
+        }));
+
+        let task_waker = Waker::from(Arc::new(FlagWaker::default()));
+        let mut cx = Context::from_waker(&task_waker);
+
+        let mut other = Node::new_alloc();
+        other.set_name("Player");
+        add_remove_child(ctx, &mut other);
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+
+        let mut enemy = Node::new_alloc();
+        enemy.set_name("Enemy1");
+        add_remove_child(ctx, &mut enemy);
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(event) => assert_eq!(event, SceneTreeEvent::NodeAdded(enemy.clone())),
+            Poll::Pending => panic!("future should be resolved"),
+        }
+
+        other.free();
+        enemy.free();
+    }
+
     // ------------------------------------------------------------------------------------------------------------------------------------------
     // 4.2+ custom callables - helper functions
 
+    #[derive(Default)]
+    struct FlagWaker {
+        woken: AtomicBool,
+    }
+
+    impl Wake for FlagWaker {
+        fn wake(self: Arc<Self>) {
+            self.woken.store(true, Ordering::SeqCst);
+        }
+    }
+
     fn add_remove_child(ctx: &TestContext, node: &mut Gd<Node>) {
         let mut tree = ctx.scene_tree.clone();
         tree.add_child(&*node);
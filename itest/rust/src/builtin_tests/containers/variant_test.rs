@@ -11,7 +11,7 @@ use std::fmt::Display;
 use godot::builtin::{
     array, dict, varray, Array, GString, NodePath, Signal, StringName, Variant, Vector2, Vector3,
 };
-use godot::builtin::{Basis, Dictionary, VariantArray, VariantOperator, VariantType};
+use godot::builtin::{Basis, Color, Dictionary, VariantArray, VariantOperator, VariantType};
 use godot::classes::{Node, Node2D};
 use godot::meta::{FromGodot, ToGodot};
 use godot::obj::{Gd, InstanceId, NewAlloc};
@@ -146,6 +146,55 @@ fn variant_bad_conversion_error_message() {
     assert_eq!(err.to_string(), "`Gd` cannot be null: null");
 }
 
+#[itest]
+fn variant_into_typed() {
+    let string = GString::from("moved");
+    assert_eq!(string.to_variant().into_typed::<GString>().unwrap(), string);
+
+    let name = StringName::from("moved");
+    assert_eq!(name.to_variant().into_typed::<StringName>().unwrap(), name);
+
+    let path = NodePath::from("a/b");
+    assert_eq!(path.to_variant().into_typed::<NodePath>().unwrap(), path);
+
+    // Arrays keep their reference semantics.
+    let mut array: Array<i64> = array![1, 2];
+    let mut moved = array.to_variant().into_typed::<Array<i64>>().unwrap();
+    moved.push(3);
+    assert_eq!(array.len(), 3);
+    array.push(4);
+    assert_eq!(moved, array![1, 2, 3, 4]);
+
+    let dict = dict! { "key": 10 };
+    let moved = dict.to_variant().into_typed::<Dictionary>().unwrap();
+    assert_eq!(moved, dict);
+
+    // Non-builtin Rust types follow the regular conversion.
+    assert_eq!(12.to_variant().into_typed::<i32>().unwrap(), 12);
+    assert_eq!("text".to_variant().into_typed::<String>().unwrap(), "text");
+
+    let variant = Vector2::new(1.0, 2.0).to_variant();
+    assert_eq!(variant.clone().into_typed::<Variant>().unwrap(), variant);
+}
+
+#[itest]
+fn variant_into_typed_bad_conversions() {
+    let err = 123
+        .to_variant()
+        .into_typed::<GString>()
+        .expect_err("i32 -> GString conversion should fail");
+    assert_eq!(err.to_string(), "cannot convert from INT to STRING: 123");
+
+    array![1, 2]
+        .to_variant()
+        .into_typed::<Array<GString>>()
+        .expect_err("Array<i64> -> Array<GString> conversion should fail");
+
+    Variant::nil()
+        .into_typed::<Dictionary>()
+        .expect_err("`nil` should not convert to `Dictionary`");
+}
+
 #[itest]
 fn variant_array_bad_conversions() {
     let i32_array: Array<i32> = array![1, 2, 160, -40];
@@ -202,6 +251,35 @@ fn variant_get_type() {
     assert_eq!(variant.get_type(), VariantType::BASIS)
 }
 
+#[itest]
+fn variant_is_type() {
+    let variant = 300i64.to_variant();
+    assert!(variant.is::<i64>());
+    assert!(variant.is::<i32>());
+    assert!(!variant.is::<i8>(), "out of range");
+    assert!(!variant.is::<f32>(), "no implicit int->float conversion");
+    assert!(!variant.is::<GString>());
+    assert!(variant.is_numeric());
+    assert!(!variant.is_string());
+
+    let variant = 2.5f64.to_variant();
+    assert!(variant.is::<f32>());
+    assert!(!variant.is::<i64>());
+    assert!(variant.is_numeric());
+
+    let variant = gstr("hello").to_variant();
+    assert!(variant.is::<GString>());
+    assert!(variant.is_string());
+    assert!(!variant.is_numeric());
+    assert!(sname("hello").to_variant().is_string());
+
+    let variant = Variant::nil();
+    assert!(variant.is::<Option<Gd<Node>>>());
+    assert!(!variant.is::<i64>());
+    assert!(!variant.is_string());
+    assert!(!variant.is_numeric());
+}
+
 #[cfg(since_api = "4.4")]
 #[itest]
 fn variant_object_id() {
@@ -293,6 +371,29 @@ fn variant_call() {
     node2d.free();
 }
 
+#[itest]
+fn variant_approx_eq() {
+    let a = 1.0.to_variant();
+    assert!(a.approx_eq(&1.000_01.to_variant(), 1e-4));
+    assert!(!a.approx_eq(&1.01.to_variant(), 1e-4));
+
+    let v = Vector3::new(1.0, 2.0, 3.0).to_variant();
+    assert!(v.approx_eq(&Vector3::new(1.0, 2.000_01, 3.0).to_variant(), 1e-4));
+    assert!(!v.approx_eq(&Vector3::new(1.0, 2.0, 3.1).to_variant(), 1e-4));
+
+    let v = Vector2::new(0.5, -0.5).to_variant();
+    assert!(v.approx_eq(&Vector2::new(0.500_01, -0.5).to_variant(), 1e-4));
+
+    let c = Color::from_rgb(0.2, 0.4, 0.6).to_variant();
+    assert!(c.approx_eq(&Color::from_rgb(0.2, 0.400_01, 0.6).to_variant(), 1e-4));
+    assert!(!c.approx_eq(&Color::from_rgb(0.3, 0.4, 0.6).to_variant(), 1e-4));
+
+    // Other types compare exactly; mismatched types never compare equal.
+    assert!("str".to_variant().approx_eq(&"str".to_variant(), 1.0));
+    assert!(!1.to_variant().approx_eq(&2.to_variant(), 10.0));
+    assert!(!1.0.to_variant().approx_eq(&1.to_variant(), 1.0));
+}
+
 #[rustfmt::skip]
 #[itest]
 fn variant_evaluate() {
@@ -488,6 +589,129 @@ fn variant_hash() {
     assert_ne!(dict! { 0: dict! { 0: 0 } }, dict! { 0: dict! { 0: 1 } });
 }
 
+#[itest]
+fn variant_deep_clone() {
+    let original = dict! {
+        "nested": dict! { "value": 1 },
+        "list": varray![1, varray![2]],
+    }
+    .to_variant();
+
+    let cloned = original.deep_clone();
+    assert_eq!(cloned, original);
+
+    let mut nested = cloned.to::<Dictionary>().at("nested").to::<Dictionary>();
+    nested.set("value", 2);
+
+    let list = cloned.to::<Dictionary>().at("list").to::<VariantArray>();
+    list.at(1).to::<VariantArray>().push(3);
+
+    let original_dict = original.to::<Dictionary>();
+    assert_eq!(
+        original_dict.at("nested"),
+        dict! { "value": 1 }.to_variant()
+    );
+    assert_eq!(
+        original_dict.at("list"),
+        varray![1, varray![2]].to_variant()
+    );
+
+    // Shallow clone shares nested containers.
+    let shallow = original.clone();
+    shallow
+        .to::<Dictionary>()
+        .at("nested")
+        .to::<Dictionary>()
+        .set("value", 3);
+    assert_eq!(
+        original_dict.at("nested"),
+        dict! { "value": 3 }.to_variant()
+    );
+
+    // Typed arrays keep their element type.
+    let typed: Array<i64> = array![1, 2];
+    let cloned = typed.to_variant().deep_clone();
+    let mut cloned_array = cloned.to::<Array<i64>>();
+    cloned_array.push(3);
+    assert_eq!(typed, array![1, 2]);
+    assert_eq!(cloned_array, array![1, 2, 3]);
+
+    // Non-container types are simply cloned.
+    assert_eq!(5.to_variant().deep_clone(), 5.to_variant());
+    assert!(Variant::nil().deep_clone().is_nil());
+}
+
+#[itest]
+fn variant_get_path() {
+    let data = dict! {
+        "player": dict! {
+            "stats": dict! { "hp": 100 },
+            "items": varray!["sword", "shield"],
+        },
+    }
+    .to_variant();
+
+    let path = [
+        "player".to_variant(),
+        "stats".to_variant(),
+        "hp".to_variant(),
+    ];
+    assert_eq!(data.get_path(&path), Some(100.to_variant()));
+
+    let path = ["player".to_variant(), "items".to_variant(), 1.to_variant()];
+    assert_eq!(data.get_path(&path), Some("shield".to_variant()));
+
+    assert_eq!(data.get_path(&[]), Some(data.clone()));
+}
+
+#[itest]
+fn variant_get_path_typed_array() {
+    let data = dict! { "items": array![10, 20] }.to_variant();
+
+    let path = ["items".to_variant(), 1.to_variant()];
+    assert_eq!(data.get_path(&path), Some(20.to_variant()));
+
+    let path = ["items".to_variant(), 2.to_variant()];
+    assert_eq!(data.try_get_path(&path).expect("valid structure"), None);
+
+    let path = ["items".to_variant(), (-1).to_variant()];
+    assert_eq!(data.try_get_path(&path).expect("valid structure"), None);
+}
+
+#[itest]
+fn variant_get_path_fail() {
+    let data = dict! {
+        "player": dict! { "hp": 100, "items": varray!["sword"] },
+    }
+    .to_variant();
+
+    // Key not found.
+    let path = ["player".to_variant(), "mana".to_variant()];
+    assert_eq!(data.get_path(&path), None);
+    assert_eq!(data.try_get_path(&path).expect("valid structure"), None);
+
+    // Array index out of bounds, or not an integer.
+    let path = ["player".to_variant(), "items".to_variant(), 3.to_variant()];
+    assert_eq!(data.get_path(&path), None);
+    let path = [
+        "player".to_variant(),
+        "items".to_variant(),
+        "x".to_variant(),
+    ];
+    assert_eq!(data.get_path(&path), None);
+    assert_eq!(data.try_get_path(&path).expect("valid structure"), None);
+
+    // Intermediate value is not a container.
+    let path = ["player".to_variant(), "hp".to_variant(), "max".to_variant()];
+    assert_eq!(data.get_path(&path), None);
+    let err = data.try_get_path(&path).expect_err("non-container");
+    assert!(
+        err.to_string().contains("expected dictionary or array"),
+        "{err}"
+    );
+    assert_eq!(err.value(), Some(&100.to_variant()));
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 fn truncate_bad<T>(original_value: i64)
@@ -13,6 +13,7 @@ use godot::classes::{Node, Resource};
 use godot::meta::error::ConvertError;
 use godot::meta::{AsArg, CowArg, FromGodot, GodotConvert, ToGodot};
 use godot::obj::{Gd, NewAlloc};
+use std::time::Duration;
 
 use crate::framework::itest;
 
@@ -326,6 +327,22 @@ fn slice_to_array() {
     assert!(to.is_err());
 }
 
+#[itest]
+fn duration_to_float_seconds() {
+    let from = Duration::from_millis(1500);
+    let variant = from.to_variant();
+    assert_eq!(variant, 1.5.to_variant());
+    assert_eq!(variant.to::<Duration>(), from);
+
+    let to = 0.25.to_variant().to::<Duration>();
+    assert_eq!(to, Duration::from_millis(250));
+
+    // Invalid conversions: negative or non-finite seconds.
+    assert!((-1.0).to_variant().try_to::<Duration>().is_err());
+    assert!(f64::NAN.to_variant().try_to::<Duration>().is_err());
+    assert!(f64::INFINITY.to_variant().try_to::<Duration>().is_err());
+}
+
 fn as_gstr_arg<'a, T: 'a + AsArg<GString>>(t: T) -> CowArg<'a, GString> {
     t.into_arg()
 }
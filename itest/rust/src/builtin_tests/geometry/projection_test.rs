@@ -11,7 +11,7 @@ use crate::framework::itest;
 
 use godot::builtin::inner::InnerProjection;
 use godot::builtin::math::assert_eq_approx;
-use godot::builtin::{real, Aabb, Projection, RealConv, Rect2, Vector2, Vector3};
+use godot::builtin::{real, Aabb, Projection, RealConv, Rect2, Vector2, Vector3, Vector4};
 
 #[itest]
 fn test_create_orthogonal() {
@@ -131,6 +131,38 @@ fn test_create_perspective() {
     }
 }
 
+#[itest]
+fn test_perspective_known_matrix() {
+    let proj = Projection::create_perspective(90.0, 1.0, 1.0, 3.0, false);
+
+    // tan(45°) = 1, so x/y scale is 1. Depth maps [near, far] to [-1, 1] in normalized device coordinates.
+    let expected = Projection::from_cols(
+        Vector4::new(1.0, 0.0, 0.0, 0.0),
+        Vector4::new(0.0, 1.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, -2.0, -1.0),
+        Vector4::new(0.0, 0.0, -3.0, 0.0),
+    );
+    assert_eq_approx!(proj, expected);
+
+    let near_point = proj * Vector4::new(0.0, 0.0, -1.0, 1.0);
+    assert_eq_approx!(near_point, Vector4::new(0.0, 0.0, -1.0, 1.0));
+
+    let far_point = proj * Vector4::new(3.0, 0.0, -3.0, 1.0);
+    assert_eq_approx!(far_point / far_point.w, Vector4::new(1.0, 0.0, 1.0, 1.0));
+}
+
+#[itest]
+fn test_inverse() {
+    let proj = Projection::create_perspective(60.0, 16.0 / 9.0, 0.1, 100.0, false);
+    assert_eq_approx!(proj * proj.inverse(), Projection::IDENTITY);
+
+    let point = Vector4::new(1.0, 2.0, -5.0, 1.0);
+    assert_eq_approx!(proj.inverse() * (proj * point), point);
+
+    let godot_inverse = InnerProjection::from_outer(&proj).inverse();
+    assert_eq_approx!(proj.inverse(), godot_inverse);
+}
+
 #[itest]
 fn test_create_frustum() {
     const TEST_DATA: [[real; 6]; 3] = [
@@ -345,6 +345,13 @@ fn move_toward() {
     let c = 5.0;
 
     assert_eq!(a.move_toward(b, c as real), a.as_inner().move_toward(b, c));
+
+    // Distance-based, and does not overshoot.
+    let origin = Vector2::ZERO;
+    let target = Vector2::new(3.0, 4.0);
+    assert_eq_approx!(origin.move_toward(target, 2.5), Vector2::new(1.5, 2.0));
+    assert_eq!(origin.move_toward(target, 5.0), target);
+    assert_eq!(origin.move_toward(target, 100.0), target);
 }
 
 #[itest]
@@ -443,6 +450,11 @@ fn snapped() {
     let b = Vector2::new(5.6, 7.8);
 
     assert_eq!(a.snapped(b), a.as_inner().snapped(b));
+
+    // Zero step leaves the component unchanged.
+    let v = Vector2::new(7.3, -2.6);
+    assert_eq_approx!(v.snapped(Vector2::new(2.0, 0.0)), Vector2::new(8.0, -2.6));
+    assert_eq_approx!(v.snapped(Vector2::new(0.5, 0.5)), Vector2::new(7.5, -2.5));
 }
 
 #[itest]
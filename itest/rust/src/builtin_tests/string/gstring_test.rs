@@ -8,7 +8,7 @@
 use std::collections::HashSet;
 
 use crate::framework::{expect_debug_panic_or_release_ok, itest};
-use godot::builtin::{GString, PackedStringArray};
+use godot::builtin::{array, dict, Array, GString, PackedStringArray};
 
 // TODO use tests from godot-rust/gdnative
 
@@ -154,6 +154,8 @@ fn string_find() {
     let s = GString::from("Hello World");
 
     assert_eq!(s.find("o"), Some(4));
+    assert_eq!(s.rfind("o"), Some(7));
+    assert_eq!(s.rfind("x"), None);
 
     // Forward
     assert_eq!(s.find_ex("o").done(), Some(4));
@@ -221,6 +223,11 @@ fn string_count() {
     assert_eq!(s.count("en", ..=10), 2);
 
     assert_eq!(s.countn("sent", ..), 2);
+
+    // Occurrences do not overlap.
+    let s = GString::from("aaaa");
+    assert_eq!(s.count("aa", ..), 2);
+    assert_eq!(s.count("aaa", ..), 1);
 }
 
 #[itest]
@@ -264,6 +271,82 @@ fn string_pad() {
 
     assert_eq!(s.pad_zeros(5), "00123.456".into());
     assert_eq!(s.pad_zeros(2), "123.456".into());
+
+    // Edge cases documented in pad_decimals() and pad_zeros().
+    assert_eq!(s.pad_decimals(0), "123".into());
+    assert_eq!(GString::from("12").pad_decimals(2), "12.00".into());
+    assert_eq!(GString::from("-12.5").pad_zeros(4), "-0012.5".into());
+}
+
+#[itest]
+fn string_case_conversion() {
+    let s = GString::from("hello_world");
+    assert_eq!(s.to_camel_case(), "helloWorld".into());
+    assert_eq!(s.to_pascal_case(), "HelloWorld".into());
+    assert_eq!(s.capitalize(), "Hello World".into());
+
+    let s = GString::from("HelloWorld");
+    assert_eq!(s.to_snake_case(), "hello_world".into());
+    assert_eq!(s.to_upper(), "HELLOWORLD".into());
+    assert_eq!(s.to_lower(), "helloworld".into());
+}
+
+#[itest]
+fn string_replace() {
+    let s = GString::from("a-b-c-d");
+    assert_eq!(s.replace_all("-", "+"), "a+b+c+d".into());
+    assert_eq!(s.replace_first("-", "+"), "a+b-c-d".into());
+    assert_eq!(s.replace_n("-", "+", 2), "a+b+c-d".into());
+    assert_eq!(s.replace_n("-", "", 10), "abcd".into());
+    assert_eq!(s.replace_n("-", "--", 3), "a--b--c--d".into());
+
+    // Edge cases: zero count, missing and empty substring.
+    assert_eq!(s.replace_n("-", "+", 0), s);
+    assert_eq!(s.replace_n("x", "+", 2), s);
+    assert_eq!(s.replace_first("x", "+"), s);
+    assert_eq!(s.replace_n("", "+", 2), s);
+    assert_eq!(s.replace_first("", "+"), s);
+    assert_eq!(GString::new().replace_n("-", "+", 1), GString::new());
+}
+
+#[itest]
+fn string_format_dict() {
+    let template = GString::from("{name} has {count} coins");
+    let values = dict! { "name": "Ferris", "count": 3 };
+    assert_eq!(template.format_dict(&values), "Ferris has 3 coins".into());
+
+    // Missing keys are left untouched.
+    let template = GString::from("{name} is {missing}");
+    assert_eq!(template.format_dict(&values), "Ferris is {missing}".into());
+
+    // Only the inner braces form a placeholder.
+    let template = GString::from("{{name}}");
+    assert_eq!(template.format_dict(&values), "{Ferris}".into());
+
+    // Multi-byte characters in keys and values.
+    let template = GString::from("{名前}さん、{挨拶}");
+    let values = dict! { "名前": "フェリス", "挨拶": "こんにちは 🦀" };
+    assert_eq!(
+        template.format_dict(&values),
+        "フェリスさん、こんにちは 🦀".into()
+    );
+
+    assert_eq!(GString::new().format_dict(&values), GString::new());
+}
+
+#[itest]
+fn string_format_array() {
+    let template = GString::from("{0} + {1} = {2}");
+    assert_eq!(template.format_array(&array![1, 2, 3]), "1 + 2 = 3".into());
+
+    let values: Array<GString> = array!["α", "ω"];
+    assert_eq!(GString::from("{1}…{0}").format_array(&values), "ω…α".into());
+
+    // Out-of-bounds indices are left untouched.
+    assert_eq!(
+        GString::from("{0} {5}").format_array(&values),
+        "α {5}".into()
+    );
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
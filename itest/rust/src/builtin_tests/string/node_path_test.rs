@@ -8,7 +8,7 @@
 use std::collections::HashSet;
 
 use crate::framework::{expect_debug_panic_or_release_ok, itest};
-use godot::builtin::{GString, NodePath};
+use godot::builtin::{GString, NodePath, StringName};
 
 #[itest]
 fn node_path_default() {
@@ -31,6 +31,67 @@ fn node_path_conversion() {
 
     assert_eq!(string, back);
 }
+
+#[itest]
+fn node_path_display() {
+    let path = NodePath::from("Level/Player:position:x");
+
+    assert_eq!(format!("{path}"), "Level/Player:position:x");
+    assert_eq!(format!("{path:?}"), "^\"Level/Player:position:x\"");
+    assert_eq!(path.get_name(1), "Player".into());
+    assert_eq!(path.get_subname(0), "position".into());
+}
+
+#[itest]
+fn node_path_from_components_iter() {
+    let names = [String::from("Level"), String::from("Player")];
+    let path = NodePath::from_components_iter(names.iter().map(String::as_str));
+    assert_eq!(path, NodePath::from("Level/Player"));
+    assert_eq!(path.get_name_count(), 2);
+
+    let single = NodePath::from_components_iter(["Player"]);
+    assert!(single.is_single_name());
+
+    assert_eq!(
+        NodePath::from_components_iter(std::iter::empty()),
+        NodePath::default()
+    );
+    assert_eq!(
+        NodePath::from_components_iter(["", ""]),
+        NodePath::default()
+    );
+
+    // Components are not escaped.
+    let nested = NodePath::from_components_iter(["/root", "Level/Player", "", "Sprite2D:texture"]);
+    assert_eq!(
+        nested,
+        NodePath::from("/root/Level/Player/Sprite2D:texture")
+    );
+    assert_eq!(nested.get_name_count(), 4);
+    assert_eq!(nested.get_subname_count(), 1);
+}
+
+#[itest]
+fn node_path_names_subnames() {
+    let path = NodePath::from("../RigidBody2D/Sprite2D:texture:resource_name");
+
+    let names: Vec<StringName> = path.names().collect();
+    assert_eq!(
+        names,
+        ["..", "RigidBody2D", "Sprite2D"].map(StringName::from)
+    );
+    assert_eq!(path.names().len(), 3);
+    assert_eq!(path.names().next_back(), Some(StringName::from("Sprite2D")));
+
+    let subnames: Vec<StringName> = path.subnames().rev().collect();
+    assert_eq!(subnames, ["resource_name", "texture"].map(StringName::from));
+    assert_eq!(path.subnames().len(), 2);
+
+    let empty = NodePath::default();
+    assert_eq!(empty.names().len(), 0);
+    assert_eq!(empty.subnames().next(), None);
+}
+
 #[itest]
 fn node_path_equality() {
     let string = NodePath::from("some string");
@@ -126,3 +187,66 @@ fn node_path_get_subname() {
         assert_eq!(path.get_subname(2), "".into());
     })
 }
+
+#[itest]
+fn node_path_depth() {
+    assert_eq!(NodePath::default().depth(), 0);
+    assert_eq!(NodePath::from("Sprite2D").depth(), 1);
+    assert_eq!(NodePath::from("../RigidBody2D/Sprite2D").depth(), 3);
+    assert_eq!(NodePath::from("Sprite2D:texture:resource_name").depth(), 1);
+    assert_eq!(NodePath::from(":position:x").depth(), 0);
+}
+
+#[itest]
+fn node_path_is_single_name() {
+    assert!(NodePath::from("Sprite2D").is_single_name());
+    assert!(NodePath::from("..").is_single_name());
+
+    assert!(!NodePath::default().is_single_name());
+    assert!(!NodePath::from("Body/Sprite2D").is_single_name());
+    assert!(!NodePath::from("Sprite2D:texture").is_single_name());
+    assert!(!NodePath::from(":texture").is_single_name());
+}
+
+#[itest]
+fn node_path_is_property_only() {
+    assert!(NodePath::from(":position").is_property_only());
+    assert!(NodePath::from(":position:x").is_property_only());
+
+    assert!(!NodePath::default().is_property_only());
+    assert!(!NodePath::from("Sprite2D").is_property_only());
+    assert!(!NodePath::from("Sprite2D:position").is_property_only());
+}
+
+#[itest]
+fn node_path_strip_subnames() {
+    let path = NodePath::from("Body/Sprite2D:texture:resource_name");
+    assert_eq!(path.strip_subnames(), "Body/Sprite2D".into());
+    assert_eq!(path.strip_subnames().get_subname_count(), 0);
+
+    let absolute = NodePath::from("/root/Main:position");
+    assert_eq!(absolute.strip_subnames(), "/root/Main".into());
+
+    // Nothing to strip.
+    assert_eq!(NodePath::from("../Node").strip_subnames(), "../Node".into());
+    assert_eq!(
+        NodePath::from(":position:x").strip_subnames(),
+        NodePath::default()
+    );
+    assert_eq!(NodePath::default().strip_subnames(), NodePath::default());
+}
+
+#[itest]
+fn node_path_strip_names() {
+    let path = NodePath::from("Body/Sprite2D:texture:resource_name");
+    assert_eq!(path.strip_names(), ":texture:resource_name".into());
+    assert_eq!(path.strip_names().get_name_count(), 0);
+
+    // Nothing to strip.
+    assert_eq!(
+        NodePath::from(":position:x").strip_names(),
+        ":position:x".into()
+    );
+    assert_eq!(NodePath::from("../Node").strip_names(), NodePath::default());
+    assert_eq!(NodePath::default().strip_names(), NodePath::default());
+}
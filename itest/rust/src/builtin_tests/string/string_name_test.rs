@@ -58,6 +58,20 @@ fn string_name_equality() {
     assert_ne!(string, different);
 }
 
+#[itest]
+fn string_name_eq_str() {
+    let name = StringName::from("jump");
+
+    assert!(name.eq_str("jump"));
+    assert!(!name.eq_str("jum"));
+    assert!(!name.eq_str("jumps"));
+    assert!(StringName::default().eq_str(""));
+    assert!(!StringName::default().eq_str("jump"));
+
+    assert!(name.eq_gstring(&GString::from("jump")));
+    assert!(!name.eq_gstring(&GString::from("Jump")));
+}
+
 #[itest]
 #[allow(clippy::eq_op)]
 fn string_name_transient_ord() {
@@ -0,0 +1,43 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::obj::NewAlloc;
+
+use crate::framework::{itest, TestContext};
+
+#[itest]
+fn camera_world_to_screen(ctx: &TestContext) {
+    use godot::builtin::Vector3;
+    use godot::classes::Camera3D;
+    use godot::private::class_macros::assert_eq_approx;
+
+    // Default orientation: looking along -Z.
+    let mut camera = Camera3D::new_alloc();
+    camera.set_position(Vector3::new(0.0, 0.0, 10.0));
+    ctx.scene_tree.clone().add_child(&camera);
+
+    let visible_rect = camera.get_viewport().unwrap().get_visible_rect();
+
+    let center = camera
+        .world_to_screen(Vector3::ZERO)
+        .expect("point in front of camera is on screen");
+    assert_eq_approx!(center, visible_rect.center());
+    assert!(camera.is_position_visible(Vector3::ZERO));
+
+    // Behind the camera.
+    assert_eq!(camera.world_to_screen(Vector3::new(0.0, 0.0, 20.0)), None);
+    assert!(!camera.is_position_visible(Vector3::new(0.0, 0.0, 20.0)));
+
+    // In front of the camera, but far outside the field of view.
+    assert_eq!(camera.world_to_screen(Vector3::new(1000.0, 0.0, 0.0)), None);
+
+    // Outside the scene tree.
+    ctx.scene_tree.clone().remove_child(&camera);
+    assert_eq!(camera.world_to_screen(Vector3::ZERO), None);
+
+    camera.free();
+}
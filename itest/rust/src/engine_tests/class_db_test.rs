@@ -0,0 +1,68 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::{StringName, VariantType};
+use godot::classes::ClassDb;
+use godot::global::{MethodFlags, PropertyHint, PropertyUsageFlags};
+use godot::meta::ToGodot;
+
+use crate::framework::itest;
+
+#[itest]
+fn class_db_class_methods() {
+    let methods = ClassDb::singleton().class_methods("Node");
+
+    let add_child = methods
+        .iter()
+        .find(|method| method.name == StringName::from("add_child"))
+        .expect("Node has add_child()");
+
+    assert_eq!(
+        add_child.params[0],
+        (StringName::from("node"), VariantType::OBJECT)
+    );
+    assert_eq!(add_child.params.len(), 3);
+    assert_eq!(add_child.default_args[0], false.to_variant());
+    assert_eq!(add_child.return_type, VariantType::NIL);
+
+    let get_child_count = methods
+        .iter()
+        .find(|method| method.name == StringName::from("get_child_count"))
+        .expect("Node has get_child_count()");
+
+    assert_eq!(get_child_count.return_type, VariantType::INT);
+    assert!(get_child_count.flags.is_set(MethodFlags::CONST));
+
+    // Inherited methods are included.
+    assert!(methods
+        .iter()
+        .any(|method| method.name == StringName::from("get_instance_id")));
+
+    assert!(ClassDb::singleton()
+        .class_methods("NonExistentClass")
+        .is_empty());
+}
+
+#[itest]
+fn class_db_class_properties() {
+    let properties = ClassDb::singleton().class_properties("Node");
+
+    let name = properties
+        .iter()
+        .find(|property| property.name == StringName::from("name"))
+        .expect("Node has name property");
+    assert_eq!(name.variant_type, VariantType::STRING_NAME);
+
+    let process_mode = properties
+        .iter()
+        .find(|property| property.name == StringName::from("process_mode"))
+        .expect("Node has process_mode property");
+    assert_eq!(process_mode.variant_type, VariantType::INT);
+    assert_eq!(process_mode.hint, PropertyHint::ENUM);
+    assert!(!process_mode.hint_string.is_empty());
+    assert!(process_mode.usage.is_set(PropertyUsageFlags::STORAGE));
+}
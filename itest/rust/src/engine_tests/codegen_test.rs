@@ -31,6 +31,31 @@ fn codegen_base_renamed() {
     obj.free();
 }
 
+#[itest]
+fn codegen_singleton_checked() {
+    use godot::classes::Engine;
+
+    assert!(Engine::is_singleton_available());
+    assert_eq!(Engine::singleton_checked(), Some(Engine::singleton()));
+}
+
+#[itest]
+fn codegen_singleton_manual_extensions() {
+    use godot::classes::Engine;
+
+    let mut engine = Engine::singleton();
+    assert_eq!(engine.current_fps(), engine.get_frames_per_second());
+    assert_eq!(engine.frame_count(), engine.get_process_frames());
+    assert_eq!(engine.physics_frames(), engine.get_physics_frames());
+
+    let original = engine.time_scale();
+    engine.set_time_scale(0.5);
+    assert_eq!(engine.time_scale(), 0.5);
+
+    engine.set_time_scale(original);
+    assert_eq!(engine.time_scale(), original);
+}
+
 #[itest]
 fn codegen_static_builtin_method() {
     let pi = GString::num(std::f64::consts::PI, 3);
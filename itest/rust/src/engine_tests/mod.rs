@@ -5,6 +5,8 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod camera_test;
+mod class_db_test;
 mod codegen_enums_test;
 mod codegen_test;
 mod engine_enum_test;
@@ -14,7 +16,13 @@ mod gfile_test;
 #[cfg(all(feature = "experimental-threads", feature = "codegen-full"))]
 mod native_audio_structures_test;
 mod native_structures_test;
+mod node3d_test;
 mod node_test;
+mod raycast_test;
+mod rigid_body_test;
 mod save_load_test;
+mod scene_builder_test;
+mod scene_tree_test;
 mod translate_test;
 mod utilities_test;
+mod viewport_test;
@@ -0,0 +1,77 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::Node3D;
+use godot::obj::NewAlloc;
+#[cfg(feature = "codegen-full")]
+use godot::obj::NewGd;
+
+use crate::framework::{itest, TestContext};
+
+#[cfg(feature = "codegen-full")]
+#[itest]
+fn node_world_aabb(ctx: &TestContext) {
+    use godot::builtin::{Aabb, Vector3};
+    use godot::classes::{BoxMesh, MeshInstance3D};
+
+    let mut mesh = BoxMesh::new_gd();
+    mesh.set_size(Vector3::new(2.0, 2.0, 2.0));
+
+    let mut mesh_instance = MeshInstance3D::new_alloc();
+    mesh_instance.set_name("Mesh");
+    mesh_instance.set_mesh(&mesh);
+    mesh_instance.set_position(Vector3::new(0.0, 5.0, 0.0));
+
+    let mut parent = Node3D::new_alloc();
+    parent.set_position(Vector3::new(10.0, 0.0, 0.0));
+    parent.add_child(&mesh_instance);
+    ctx.scene_tree.clone().add_child(&parent);
+
+    let local = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(2.0, 2.0, 2.0));
+    let expected = Aabb::new(Vector3::new(9.0, 4.0, -1.0), Vector3::new(2.0, 2.0, 2.0));
+    assert_eq!(mesh_instance.get_aabb(), local);
+    assert_eq!(mesh_instance.world_aabb(), expected);
+    assert_eq!(parent.global_aabb("Mesh"), Some(expected));
+    assert_eq!(parent.global_aabb("Missing"), None);
+
+    parent.free();
+}
+
+#[itest]
+fn node3d_global_transform_mut(ctx: &TestContext) {
+    use godot::builtin::math::assert_eq_approx;
+    use godot::builtin::real_consts::FRAC_PI_2;
+    use godot::builtin::{Basis, Transform3D, Vector3};
+
+    let mut parent = Node3D::new_alloc();
+    parent.set_position(Vector3::new(1.0, 0.0, 0.0));
+    let mut node = Node3D::new_alloc();
+    parent.add_child(&node);
+    ctx.scene_tree.clone().add_child(&parent);
+
+    {
+        let mut transform = node.global_transform_mut();
+        assert_eq!(transform.origin, Vector3::new(1.0, 0.0, 0.0));
+
+        transform
+            .translate_by(Vector3::new(0.0, 2.0, 0.0))
+            .rotate_by(Vector3::UP, FRAC_PI_2)
+            .scale_by(Vector3::splat(2.0));
+
+        // Changes accumulate in the guard; the node is only updated on drop.
+        assert_eq!(transform.origin, Vector3::new(1.0, 2.0, 0.0));
+    }
+
+    let expected = Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, FRAC_PI_2).scaled(Vector3::splat(2.0)),
+        Vector3::new(1.0, 2.0, 0.0),
+    );
+    assert_eq_approx!(node.get_global_transform(), expected);
+    assert_eq_approx!(node.get_position(), Vector3::new(0.0, 2.0, 0.0));
+
+    parent.free();
+}
@@ -8,11 +8,13 @@
 use std::str::FromStr;
 
 use godot::builtin::{NodePath, Variant};
-use godot::classes::{Node, Node3D, PackedScene, SceneTree};
+use godot::classes::node::InternalMode;
+use godot::classes::{Node, Node2D, Node3D, PackedScene, SceneTree};
 use godot::global;
-use godot::obj::{NewAlloc, NewGd};
+use godot::obj::{Gd, NewAlloc, NewGd};
+use godot::register::{godot_api, GodotClass};
 
-use crate::framework::{itest, TestContext};
+use crate::framework::{expect_panic, itest, TestContext};
 
 #[itest]
 fn node_get_node() {
@@ -84,6 +86,382 @@ fn node_scene_tree() {
     child.free();
 }
 
+#[itest]
+fn node_to_packed_scene() {
+    let mut child = Node::new_alloc();
+    child.set_name("kid");
+
+    let mut parent = Node::new_alloc();
+    parent.set_name("parent");
+    parent.add_child(&child);
+    child.set_owner(&parent);
+
+    let scene = parent.to_packed_scene().expect("pack succeeds");
+    let instance = scene.instantiate().expect("instantiate succeeds");
+    assert_eq!(instance.get_name(), "parent".into());
+    assert_eq!(instance.get_child_count(), 1);
+
+    instance.free();
+    parent.free();
+}
+
+#[itest]
+fn node_save_as_scene() {
+    let mut node = Node3D::new_alloc();
+    node.set_name("saved");
+
+    assert!(node.save_as_scene("no_such_path").is_err());
+
+    node.save_as_scene("res://test_scene.tscn")
+        .expect("save succeeds");
+    let scene = godot::tools::load::<PackedScene>("res://test_scene.tscn");
+    let instance = scene.instantiate_as::<Node3D>();
+    assert_eq!(instance.get_name(), "saved".into());
+
+    instance.free();
+    node.free();
+
+    let file_path = concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/test_scene.tscn");
+    std::fs::remove_file(file_path).expect("remove test file");
+}
+
+#[itest]
+fn node_tree_to_string() {
+    let mut root = Node::new_alloc();
+    root.set_name("root");
+
+    let mut first = Node3D::new_alloc();
+    first.set_name("first");
+    let mut nested = Node::new_alloc();
+    nested.set_name("nested");
+    first.add_child(&nested);
+
+    let mut second = Node::new_alloc();
+    second.set_name("second");
+
+    root.add_child(&first);
+    root.add_child(&second);
+
+    let expected = format!(
+        "root (Node#{})\n┠╴first (Node3D#{})\n┃  ┖╴nested (Node#{})\n┖╴second (Node#{})",
+        root.instance_id(),
+        first.instance_id(),
+        nested.instance_id(),
+        second.instance_id(),
+    );
+    assert_eq!(root.tree_to_string(), expected);
+
+    let expected = format!("nested (Node#{})", nested.instance_id());
+    assert_eq!(nested.tree_to_string(), expected);
+
+    root.free();
+}
+
+#[itest]
+fn node_children_iterators() {
+    let mut root = Node::new_alloc();
+    root.set_name("root");
+
+    let mut first = Node3D::new_alloc();
+    first.set_name("first");
+    let mut nested = Node::new_alloc();
+    nested.set_name("nested");
+    first.add_child(&nested);
+
+    let mut second = Node::new_alloc();
+    second.set_name("second");
+
+    root.add_child(&first);
+    root.add_child(&second);
+
+    let names = |iter: &mut dyn Iterator<Item = Gd<Node>>| -> Vec<String> {
+        iter.map(|node| node.get_name().to_string()).collect()
+    };
+
+    assert_eq!(names(&mut root.children()), ["first", "second"]);
+    assert_eq!(names(&mut nested.children()), Vec::<String>::new());
+    assert_eq!(
+        names(&mut root.descendants()),
+        ["first", "nested", "second"]
+    );
+    assert_eq!(names(&mut nested.ancestors()), ["first", "root"]);
+    assert_eq!(names(&mut root.ancestors()), Vec::<String>::new());
+
+    let node3ds: Vec<Gd<Node3D>> = root.children_of::<Node3D>().collect();
+    assert_eq!(node3ds, [first]);
+
+    // Removing children while iterating still visits every child.
+    let mut removed = vec![];
+    for child in root.children() {
+        root.remove_child(&child);
+        removed.push(child.get_name().to_string());
+        child.free();
+    }
+    assert_eq!(removed, ["first", "second"]);
+    assert_eq!(root.get_child_count(), 0);
+
+    root.free();
+}
+
+#[itest]
+fn node_traverse_bfs_dfs() {
+    // root
+    // ├─ a
+    // │  ├─ a1
+    // │  └─ a2
+    // └─ b
+    //    └─ b1
+    let mut root = Node::new_alloc();
+    root.set_name("root");
+
+    let make_child = |parent: &Gd<Node>, name: &str| {
+        let mut child = Node::new_alloc();
+        child.set_name(name);
+        parent.clone().add_child(&child);
+        child
+    };
+
+    let a = make_child(&root, "a");
+    let b = make_child(&root, "b");
+    make_child(&a, "a1");
+    make_child(&a, "a2");
+    let b1 = make_child(&b, "b1");
+
+    let names = |iter: &mut dyn Iterator<Item = Gd<Node>>| -> Vec<String> {
+        iter.map(|node| node.get_name().to_string()).collect()
+    };
+
+    assert_eq!(
+        names(&mut root.traverse_bfs()),
+        ["root", "a", "b", "a1", "a2", "b1"]
+    );
+    assert_eq!(
+        names(&mut root.traverse_dfs()),
+        ["root", "a", "a1", "a2", "b", "b1"]
+    );
+    assert_eq!(names(&mut b1.traverse_bfs()), ["b1"]);
+
+    // Nodes freed during traversal are skipped.
+    let mut bfs = root.traverse_bfs();
+    assert_eq!(bfs.next(), Some(root.clone()));
+    assert_eq!(bfs.next(), Some(a.clone()));
+    b.free();
+    assert_eq!(names(&mut bfs), ["a1", "a2"]);
+
+    root.free();
+}
+
+#[itest]
+fn node_path_from_root(ctx: &TestContext) {
+    let mut level = Node::new_alloc();
+    level.set_name("Level");
+    let mut player = Node3D::new_alloc();
+    player.set_name("Player");
+    level.add_child(&player);
+
+    // Out of tree: path starts at topmost ancestor.
+    assert_eq!(level.path_from_root(), NodePath::from("Level"));
+    assert_eq!(player.path_from_root(), NodePath::from("Level/Player"));
+
+    // In tree: absolute path.
+    let mut root = ctx.scene_tree.clone();
+    root.add_child(&level);
+    let expected = NodePath::from(format!("{}/Level/Player", root.get_path()));
+    assert_eq!(player.path_from_root(), expected);
+    assert_eq!(player.path_from_root(), player.get_path());
+
+    root.remove_child(&level);
+    level.free();
+}
+
+#[itest]
+fn node_add_child_deferred(ctx: &TestContext) {
+    let mut parent = Node::new_alloc();
+    ctx.scene_tree.clone().add_child(&parent);
+
+    let child = Node::new_alloc();
+    parent.add_child_deferred(&child);
+
+    // Child is only added at the end of the frame (checked in `test_add_child_deferred` in `SpecialTests.gd`); the parent is freed
+    // (together with the child) after that.
+    assert_eq!(parent.get_child_count(), 0);
+    assert_eq!(child.get_parent(), None);
+    parent.queue_free();
+}
+
+// Used in `SpecialTests.gd`, which can let frames pass between calls.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+pub struct NodeFrameTest;
+
+#[godot_api]
+impl NodeFrameTest {
+    #[func]
+    fn add_child_deferred(mut parent: Gd<Node>, child: Gd<Node>) {
+        parent.add_child_deferred(&child);
+    }
+}
+
+#[itest]
+fn node_remove_and_free_children() {
+    let mut parent = Node::new_alloc();
+    let first = Node::new_alloc();
+    let second = Node3D::new_alloc();
+    parent.add_child(&first);
+    parent.add_child(&second);
+
+    parent.remove_all_children();
+    assert_eq!(parent.get_child_count(), 0);
+    assert_eq!(first.get_parent(), None);
+    assert_eq!(second.get_parent(), None);
+
+    parent.add_child(&first);
+    parent.add_child(&second);
+    parent.queue_free_children();
+    assert!(first.is_queued_for_deletion());
+    assert!(second.is_queued_for_deletion());
+    assert!(!parent.is_queued_for_deletion());
+
+    // Children are still in the tree until the end of the frame.
+    assert_eq!(parent.get_child_count(), 2);
+    parent.queue_free();
+}
+
+#[itest]
+fn node_move_among_siblings() {
+    let mut parent = Node::new_alloc();
+    let mut children: Vec<Gd<Node>> = ["A", "B", "C", "D"]
+        .into_iter()
+        .map(|name| {
+            let mut child = Node::new_alloc();
+            child.set_name(name);
+            parent.add_child(&child);
+            child
+        })
+        .collect();
+
+    let order = |parent: &Gd<Node>| -> String {
+        parent
+            .children()
+            .map(|child| child.get_name().to_string())
+            .collect()
+    };
+
+    children[1].move_to_front();
+    assert_eq!(order(&parent), "ACDB");
+
+    children[3].move_to_back();
+    assert_eq!(order(&parent), "DACB");
+
+    children[0].move_to_sibling_index(2);
+    assert_eq!(order(&parent), "DCAB");
+
+    let (b, d) = (children[1].clone(), children[3].clone());
+    children[1].swap_with_sibling(&d);
+    assert_eq!(order(&parent), "BCAD");
+    children[3].swap_with_sibling(&b);
+    assert_eq!(order(&parent), "DCAB");
+
+    // Swapping with itself is a no-op.
+    let a = children[2].clone();
+    children[2].swap_with_sibling(&a);
+    assert_eq!(order(&parent), "DCAB");
+
+    expect_panic("index out of bounds", || {
+        children[0].clone().move_to_sibling_index(4);
+    });
+
+    let orphan = Node::new_alloc();
+    expect_panic("swap with non-sibling", || {
+        children[0].clone().swap_with_sibling(&orphan);
+    });
+
+    // Without parent, moving does nothing.
+    let mut orphan_mut = orphan.clone();
+    orphan_mut.move_to_front();
+    orphan_mut.move_to_sibling_index(3);
+
+    // Internal children are moved within their own group, even if the parent has no regular children.
+    let mut internal_parent = Node::new_alloc();
+    let mut internal: Vec<Gd<Node>> = ["X", "Y"]
+        .into_iter()
+        .map(|name| {
+            let mut child = Node::new_alloc();
+            child.set_name(name);
+            internal_parent
+                .add_child_ex(&child)
+                .internal(InternalMode::BACK)
+                .done();
+            child
+        })
+        .collect();
+
+    internal[0].move_to_front();
+    let internal_order: String = (0..2)
+        .map(|i| {
+            internal_parent
+                .get_child_ex(i)
+                .include_internal(true)
+                .done()
+                .unwrap()
+                .get_name()
+                .to_string()
+        })
+        .collect();
+    assert_eq!(internal_order, "YX");
+
+    internal_parent.free();
+    orphan.free();
+    parent.free();
+}
+
+#[itest]
+fn node_get_or_create_child() {
+    let mut parent = Node::new_alloc();
+
+    let created = parent.get_or_create_child::<Node3D>("Manager");
+    assert_eq!(created.get_name(), "Manager".into());
+    assert_eq!(parent.get_child_count(), 1);
+
+    let found = parent.get_or_create_child::<Node3D>("Manager");
+    assert_eq!(found, created);
+    assert_eq!(parent.get_child_count(), 1);
+
+    let mut parent_copy = parent.clone();
+    expect_panic("existing child has other type", move || {
+        parent_copy.get_or_create_child::<Node2D>("Manager");
+    });
+
+    // Paths are not names.
+    for invalid in ["", "..", "Manager/Sub", "Manager:position"] {
+        let mut parent_copy = parent.clone();
+        expect_panic("invalid node name", move || {
+            parent_copy.get_or_create_child::<Node3D>(invalid);
+        });
+    }
+    assert_eq!(parent.get_child_count(), 1);
+
+    parent.free();
+}
+
+#[itest]
+fn node_get_or_create_child_in_tree(ctx: &TestContext) {
+    let mut parent = Node::new_alloc();
+    ctx.scene_tree.clone().add_child(&parent);
+    assert!(parent.is_inside_tree());
+
+    let created = parent.get_or_create_child::<Node3D>("Manager");
+    assert!(created.is_inside_tree());
+    assert_eq!(created.get_parent(), Some(parent.clone()));
+
+    // Second call in the same frame finds the existing child instead of adding a renamed duplicate.
+    let found = parent.get_or_create_child::<Node3D>("Manager");
+    assert_eq!(found, created);
+    assert_eq!(parent.get_child_count(), 1);
+
+    parent.free();
+}
+
 #[itest]
 fn node_call_group(ctx: &TestContext) {
     let mut node = ctx.scene_tree.clone();
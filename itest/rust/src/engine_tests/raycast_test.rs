@@ -0,0 +1,56 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use godot::builtin::{dict, Dictionary, Rid, Vector3};
+use godot::classes::{Node, RayCast3D, World3D};
+use godot::obj::{NewAlloc, NewGd};
+use godot::tools::RaycastHit;
+
+use crate::framework::itest;
+
+#[itest]
+fn raycast_hit_from_dictionary() {
+    assert_eq!(RaycastHit::from_dictionary(&Dictionary::new()), None);
+
+    let node = Node::new_alloc();
+    let result = dict! {
+        "position": Vector3::new(1.0, 2.0, 3.0),
+        "normal": Vector3::UP,
+        "collider": node.clone(),
+        "collider_id": node.instance_id().to_i64(),
+        "rid": Rid::Invalid,
+        "shape": 2,
+    };
+
+    let hit = RaycastHit::from_dictionary(&result).expect("non-empty result");
+    assert_eq!(hit.position, Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(hit.normal, Vector3::UP);
+    assert_eq!(hit.collider, Some(node.clone().upcast()));
+    assert_eq!(hit.collider_id, Some(node.instance_id()));
+    assert_eq!(hit.rid, Rid::Invalid);
+    assert_eq!(hit.shape, 2);
+    assert_eq!(hit.face_index, -1);
+
+    node.free();
+}
+
+#[itest]
+fn raycast_immediate_cast_empty_world() {
+    let world = World3D::new_gd();
+
+    let hit = RayCast3D::immediate_cast(
+        Vector3::ZERO,
+        Vector3::new(0.0, -100.0, 0.0),
+        u32::MAX,
+        &world,
+        &[],
+    );
+
+    assert_eq!(hit, None);
+}
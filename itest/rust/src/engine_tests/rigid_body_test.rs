@@ -0,0 +1,35 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use godot::builtin::Vector3;
+use godot::classes::physics_server_3d::BodyState;
+use godot::classes::{PhysicsServer3D, RigidBody3D};
+use godot::obj::NewAlloc;
+
+use crate::framework::{itest, TestContext};
+
+#[itest]
+fn rigid_body_physics_impulses(ctx: &TestContext) {
+    let mut body = RigidBody3D::new_alloc();
+    ctx.scene_tree.clone().add_child(&body);
+
+    body.physics_impulses()
+        .central_impulse(Vector3::new(2.0, 0.0, 0.0))
+        .central_impulse(Vector3::new(0.0, 4.0, 0.0))
+        .central_force(Vector3::ZERO)
+        .apply();
+
+    // Impulses change the velocity immediately, without waiting for a physics step. Default mass is 1.
+    let velocity = PhysicsServer3D::singleton()
+        .body_get_state(body.get_rid(), BodyState::LINEAR_VELOCITY)
+        .to::<Vector3>();
+    assert_eq!(velocity, Vector3::new(2.0, 4.0, 0.0));
+
+    body.free();
+}
@@ -0,0 +1,39 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::Vector3;
+use godot::classes::{Node, Node3D};
+use godot::tools::SceneBuilder;
+
+use crate::framework::itest;
+
+#[itest]
+fn scene_builder_nested_children() {
+    let root = SceneBuilder::<Node3D>::new("Player")
+        .child::<Node3D>("Pivot", |pivot| {
+            pivot
+                .configure(|node| node.set_position(Vector3::new(0.0, 1.5, 0.0)))
+                .child::<Node>("Camera", |camera| camera)
+        })
+        .child::<Node3D>("Marker", |marker| marker.set_property("visible", false))
+        .build();
+
+    assert_eq!(root.get_name(), "Player".into());
+    assert_eq!(root.get_child_count(), 2);
+
+    let pivot = root.get_node_as::<Node3D>("Pivot");
+    assert_eq!(pivot.get_position(), Vector3::new(0.0, 1.5, 0.0));
+    assert_eq!(pivot.get_index(), 0, "children keep declaration order");
+
+    let camera = root.get_node_as::<Node>("Pivot/Camera");
+    assert_eq!(camera.get_parent(), Some(pivot.upcast()));
+
+    let marker = root.get_node_as::<Node3D>("Marker");
+    assert!(!marker.is_visible());
+
+    root.free();
+}
@@ -0,0 +1,43 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{SceneTree, SceneTreeTimer};
+use godot::obj::Gd;
+use godot::register::{godot_api, GodotClass};
+
+use crate::framework::{itest, TestContext};
+
+#[itest]
+fn scene_tree_timer_constructors(ctx: &TestContext) {
+    let tree = ctx.scene_tree.get_tree().unwrap();
+
+    let idle = SceneTreeTimer::idle(&tree, 2.5);
+    assert_eq!(idle.get_time_left(), 2.5);
+
+    let physics = SceneTreeTimer::physics(&tree, 0.25);
+    assert_eq!(physics.get_time_left(), 0.25);
+
+    // Which frames advance each timer is checked in `test_scene_tree_timer_constructors` in `SpecialTests.gd`.
+}
+
+// Used in `SpecialTests.gd`, which can let frames pass between calls.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+pub struct SceneTreeFrameTest;
+
+#[godot_api]
+impl SceneTreeFrameTest {
+    #[func]
+    fn idle_timer(tree: Gd<SceneTree>, seconds: f64) -> Gd<SceneTreeTimer> {
+        SceneTreeTimer::idle(&tree, seconds)
+    }
+
+    #[func]
+    fn physics_timer(tree: Gd<SceneTree>, seconds: f64) -> Gd<SceneTreeTimer> {
+        SceneTreeTimer::physics(&tree, seconds)
+    }
+}
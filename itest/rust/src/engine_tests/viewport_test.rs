@@ -0,0 +1,46 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::obj::NewAlloc;
+
+use crate::framework::{itest, TestContext};
+
+#[itest]
+fn viewport_project_mouse_ray(ctx: &TestContext) {
+    use godot::builtin::real_consts::FRAC_PI_2;
+    use godot::builtin::{Plane, Vector3};
+    use godot::classes::Camera3D;
+    use godot::private::class_macros::assert_eq_approx;
+
+    // Camera looking straight down at the ground.
+    let mut camera = Camera3D::new_alloc();
+    camera.set_position(Vector3::new(0.0, 10.0, 0.0));
+    camera.set_rotation(Vector3::new(-FRAC_PI_2, 0.0, 0.0));
+    ctx.scene_tree.clone().add_child(&camera);
+
+    let viewport = camera.get_viewport().unwrap();
+    let mouse_position = viewport.get_mouse_position();
+
+    let ray = viewport.project_mouse_ray(&camera);
+    assert_eq!(ray.origin, camera.project_ray_origin(mouse_position));
+    assert_eq!(ray.direction, camera.project_ray_normal(mouse_position));
+
+    let ground = viewport
+        .screen_to_world_plane(&camera, Plane::new(Vector3::UP, 0.0))
+        .expect("ray hits the ground");
+    assert_eq_approx!(ground.y, 0.0);
+    assert_eq_approx!(
+        ray.intersect_plane(Plane::new(Vector3::UP, 0.0)).unwrap(),
+        ground
+    );
+
+    // Plane above the camera: the ray points away from it.
+    let ceiling = viewport.screen_to_world_plane(&camera, Plane::new(Vector3::UP, 20.0));
+    assert_eq!(ceiling, None);
+
+    camera.free();
+}
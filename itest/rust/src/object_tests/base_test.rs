@@ -47,6 +47,18 @@ fn base_access_unbound_no_field() {
     obj.free();
 }
 
+#[itest]
+fn base_tuple_struct() {
+    let obj = TupleBased::new_alloc();
+    {
+        let guard = obj.bind();
+        assert_eq!(guard.0, 42);
+        assert_eq!(guard.base().instance_id(), obj.instance_id());
+    }
+
+    obj.free();
+}
+
 #[itest]
 fn base_display() {
     let obj = Based::new_alloc();
@@ -207,6 +219,18 @@ mod renamed_bases {
     }
 }
 
+// Base class spelled through an import alias; must not be rejected by the derive macro.
+mod aliased_base {
+    use godot::classes::Node2D as N2;
+    use godot::prelude::*;
+
+    #[derive(GodotClass)]
+    #[class(init, base = Node2D)]
+    pub struct AliasedBase {
+        base: Base<N2>,
+    }
+}
+
 impl Based {
     fn access_gd_self(&self) -> Gd<Self> {
         use godot::obj::WithBaseField as _;
@@ -229,3 +253,7 @@ impl Baseless {
         })
     }
 }
+
+#[derive(GodotClass)]
+#[class(init, base=Node2D)]
+struct TupleBased(#[init(val = 42)] i32, Base<Node2D>);
@@ -24,6 +24,14 @@ pub mod rename {
     pub struct RepeatMe {}
 }
 
+pub mod rename_string {
+    use super::*;
+
+    #[derive(GodotClass)]
+    #[class(rename = "NoRepeatStr", init)]
+    pub struct RepeatMe {}
+}
+
 #[itest]
 fn renaming_changes_the_name() {
     assert_ne!(
@@ -33,3 +41,44 @@ fn renaming_changes_the_name() {
     assert_eq!(dont_rename::RepeatMe::class_name().to_string(), "RepeatMe");
     assert_eq!(rename::RepeatMe::class_name().to_string(), "NoRepeat");
 }
+
+#[itest]
+fn renaming_with_string_literal() {
+    assert_eq!(
+        rename_string::RepeatMe::class_name().to_string(),
+        "NoRepeatStr"
+    );
+
+    // Godot sees the renamed class.
+    let obj = rename_string::RepeatMe::new_gd();
+    assert_eq!(obj.get_class(), "NoRepeatStr".into());
+    assert!(obj.is_class("NoRepeatStr"));
+}
+
+#[itest]
+fn renamed_class_to_rust_name() {
+    use godot::register::class_name_to_rust_name;
+
+    assert_eq!(
+        class_name_to_rust_name(&StringName::from("NoRepeat")),
+        Some(std::any::type_name::<rename::RepeatMe>())
+    );
+    assert_eq!(
+        class_name_to_rust_name(&StringName::from("RepeatMe")),
+        Some(std::any::type_name::<dont_rename::RepeatMe>())
+    );
+
+    // Engine classes and unknown names have no Rust type.
+    assert_eq!(class_name_to_rust_name(&StringName::from("Node")), None);
+    assert_eq!(
+        class_name_to_rust_name(&StringName::from("DoesNotExist")),
+        None
+    );
+
+    let obj = rename_string::RepeatMe::new_gd();
+    assert_eq!(
+        obj.upcast_ref::<Object>().get_class_rust_type(),
+        Some(std::any::type_name::<rename_string::RepeatMe>())
+    );
+    assert_eq!(RefCounted::new_gd().get_class_rust_type(), None);
+}
@@ -366,6 +366,26 @@ fn dyn_gd_multiple_traits() {
     obj.free();
 }
 
+#[itest]
+fn dyn_gd_pass_through_func() {
+    let exchanger = DynGdExchanger::new_gd();
+    let health = Gd::from_object(RefcHealth { hp: 27 }).into_dyn::<dyn Health>();
+
+    // Parameters: converted from Variant, trait object is resolved from the dynamic class.
+    let result = exchanger.clone().upcast::<RefCounted>().call(
+        "total_hitpoints",
+        &[health.to_variant(), health.to_variant()],
+    );
+    assert_eq!(result.to::<u32>(), 54);
+
+    // Return value: `DynGd` arrives as a plain object and can be turned back into a `DynGd`.
+    let result = exchanger
+        .upcast::<RefCounted>()
+        .call("make_health", &[12.to_variant()]);
+    let back: DynGd<RefCounted, dyn Health> = result.to();
+    assert_eq!(back.dyn_bind().get_hitpoints(), 12);
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Example symbols
 
@@ -445,3 +465,25 @@ impl InstanceIdProvider for foreign::NodeHealth {
         self.base().instance_id()
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Check that DynGd can be used in #[func] signatures.
+
+#[derive(GodotClass)]
+#[class(init)]
+struct DynGdExchanger {}
+
+#[godot_api]
+impl DynGdExchanger {
+    #[func]
+    fn total_hitpoints(&self, a: DynGd<Object, dyn Health>, b: DynGd<Object, dyn Health>) -> u32 {
+        a.dyn_bind().get_hitpoints() as u32 + b.dyn_bind().get_hitpoints() as u32
+    }
+
+    #[func]
+    fn make_health(&self, hp: u8) -> DynGd<RefCounted, dyn Health> {
+        Gd::from_object(RefcHealth { hp })
+            .into_dyn::<dyn Health>()
+            .upcast()
+    }
+}
@@ -9,7 +9,7 @@ use godot::builtin::{Variant, Vector3};
 use godot::classes::{Node, Node3D, Object};
 use godot::meta::error::CallError;
 use godot::meta::{FromGodot, ToGodot};
-use godot::obj::{InstanceId, NewAlloc};
+use godot::obj::{EngineCallable, InstanceId, NewAlloc};
 use std::error::Error;
 
 use crate::framework::{expect_panic, itest, runs_release};
@@ -42,6 +42,61 @@ fn dynamic_call_with_args() {
     node.free();
 }
 
+#[itest]
+fn dynamic_call_typed() {
+    let mut node = Node3D::new_alloc();
+
+    let expected_pos = Vector3::new(2.5, 6.42, -1.11);
+
+    node.call1::<(), _>("set_position", expected_pos).unwrap();
+    let actual_pos: Vector3 = node.call0("get_position").unwrap();
+    let is_visible: bool = node.call_method("is_visible", &[]).unwrap();
+
+    assert_eq!(actual_pos, expected_pos);
+    assert!(is_visible);
+
+    // Call succeeds, but result cannot be converted.
+    let call_error = node
+        .call0::<i64>("get_position")
+        .expect_err("expected failed conversion");
+
+    assert_eq!(call_error.class_name(), Some("Node3D"));
+    assert_eq!(call_error.method_name(), "get_position");
+    assert!(call_error.source().is_some());
+
+    node.free();
+}
+
+#[itest]
+fn dynamic_call_engine_callable() {
+    let node = Node3D::new_alloc();
+
+    let mut set_position = EngineCallable::<(Vector3,), ()>::new(node.clone(), "set_position");
+    let mut get_position = EngineCallable::<(), Vector3>::new(node.clone(), "get_position");
+    assert_eq!(get_position.method_name(), &"get_position".into());
+
+    for expected_pos in [Vector3::ZERO, Vector3::new(2.5, 6.42, -1.11), Vector3::ONE] {
+        set_position.call((expected_pos,));
+        assert_eq!(get_position.call(()), expected_pos);
+    }
+
+    // Wrong return type.
+    let mut get_position_int = EngineCallable::<(), i64>::new(node.clone(), "get_position");
+    let call_error = get_position_int
+        .try_call(())
+        .expect_err("expected failed conversion");
+    assert_eq!(call_error.method_name(), "get_position");
+    assert!(call_error.source().is_some());
+
+    // Non-existent method.
+    let mut missing = EngineCallable::<(i64, bool), ()>::new(node.clone(), "no_such_method");
+    expect_panic("call non-existent method", || {
+        missing.call((1, true));
+    });
+
+    node.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Erroneous dynamic calls to #[func]
 
@@ -83,6 +138,11 @@ fn dynamic_call_with_too_few_args() {
     assert_eq!(source.class_name(), Some("ObjPayload"));
     assert_eq!(source.method_name(), "take_1_int");
 
+    // Whole chain: only one level deep.
+    let chain: Vec<_> = call_error.source_chain().collect();
+    assert_eq!(chain.len(), 1);
+    assert_eq!(chain[0].to_string(), source.to_string());
+
     obj.free();
 }
 
@@ -104,7 +164,7 @@ fn dynamic_call_with_too_many_args() {
     assert_eq!(call_error.method_name(), "call");
     assert_eq!(
         call_error.to_string(),
-        "godot-rust function call failed: Object::call(&\"take_1_int\", [va] 42, 43)\
+        "godot-rust function call failed: Object::call(&\"take_1_int\", [va] #0(INT):42, #1(INT):43)\
         \n  Source: ObjPayload::take_1_int()\
         \n    Reason: function has 1 parameter, but received 2 arguments"
     );
@@ -130,12 +190,18 @@ fn dynamic_call_parameter_mismatch() {
     assert_eq!(call_error.method_name(), "call");
     assert_eq!(
         call_error.to_string(),
-        "godot-rust function call failed: Object::call(&\"take_1_int\", [va] \"string\")\
+        "godot-rust function call failed: Object::call(&\"take_1_int\", [va] #0(STRING):\"string\")\
         \n  Source: ObjPayload::take_1_int()\
         \n    Reason: parameter #0 (i64) conversion\
         \n  Source: cannot convert from STRING to INT: \"string\""
     );
 
+    // Chain: outer call -> #[func] call -> conversion error.
+    let chain: Vec<_> = call_error.source_chain().collect();
+    assert_eq!(chain.len(), 2);
+    assert!(chain[0].downcast_ref::<CallError>().is_some());
+    assert_eq!(chain[1].to_string(), "cannot convert from STRING to INT");
+
     obj.free();
 }
 
@@ -202,7 +268,7 @@ fn dynamic_call_with_too_few_args_engine() {
     assert_eq!(call_error.method_name(), "call");
     assert_eq!(
         call_error.to_string(),
-        "godot-rust function call failed: Object::call(&\"rpc_config\", [va] \"some_method\")\
+        "godot-rust function call failed: Object::call(&\"rpc_config\", [va] #0(STRING):\"some_method\")\
         \n    Reason: function has 2 parameters, but received 1 argument"
     );
 
@@ -239,7 +305,7 @@ fn dynamic_call_with_too_many_args_engine() {
     assert_eq!(call_error.method_name(), "call");
     assert_eq!(
         call_error.to_string(),
-        "godot-rust function call failed: Object::call(&\"rpc_config\", [va] \"some_method\", null, 123)\
+        "godot-rust function call failed: Object::call(&\"rpc_config\", [va] #0(STRING):\"some_method\", #1(NIL):null, #2(INT):123)\
         \n    Reason: function has 2 parameters, but received 3 arguments"
     );
 
@@ -270,13 +336,35 @@ fn dynamic_call_parameter_mismatch_engine() {
     assert_eq!(call_error.method_name(), "call");
     assert_eq!(
         call_error.to_string(),
-        "godot-rust function call failed: Object::call(&\"set_name\", [va] 123)\
+        "godot-rust function call failed: Object::call(&\"set_name\", [va] #0(INT):123)\
         \n    Reason: parameter #1 -- cannot convert from INT to STRING"
     );
 
     node.free();
 }
 
+#[itest]
+fn dynamic_call_no_panic_catch() {
+    let mut obj = ObjPayload::new_alloc();
+
+    let result = obj.call("take_1_int_no_catch", &[42.to_variant()]);
+    assert_eq!(result, 42.to_variant());
+
+    // Argument validation does not depend on panic catching.
+    let call_error = obj
+        .try_call("take_1_int_no_catch", &[])
+        .expect_err("expected failed call");
+
+    let source = call_error.source().expect("must have source CallError");
+    assert_eq!(
+        source.to_string(),
+        "godot-rust function call failed: ObjPayload::take_1_int_no_catch()\
+        \n    Reason: function has 1 parameter, but received 0 arguments"
+    );
+
+    obj.free();
+}
+
 #[itest(skip)]
 fn dynamic_call_return_mismatch() {
     // Cannot easily test this, as both calls to #[func] and Godot APIs are either strongly typed and correct (ensured by codegen),
@@ -30,6 +30,9 @@ impl IObject for GetPropertyListTest {
             PropertyInfo::new_export::<Vector3>("some_group_my_vector_3"),
             PropertyInfo::new_subgroup("my_subgroup", "some_subgroup_"),
             PropertyInfo::new_export::<Option<Gd<Node>>>("some_subgroup_node"),
+            PropertyInfo::new_var::<i64>("hinted_property")
+                .with_hint(PropertyHint::RANGE, "0,100,1")
+                .with_usage(PropertyUsageFlags::STORAGE | PropertyUsageFlags::EDITOR),
         ]
     }
 }
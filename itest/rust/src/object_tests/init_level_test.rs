@@ -7,7 +7,7 @@
 
 use crate::framework::itest;
 use godot::init::InitLevel;
-use godot::obj::NewAlloc;
+use godot::obj::{GodotClass as _, NewAlloc};
 use godot::register::{godot_api, GodotClass};
 use std::sync::atomic::{AtomicBool, Ordering};
 
@@ -17,6 +17,10 @@ static HAS_RUN: AtomicBool = AtomicBool::new(false);
 #[class(base = Object, init)]
 struct SomeObject {}
 
+#[derive(GodotClass)]
+#[class(base = Object, init, init_level = Servers)]
+struct ServersObject {}
+
 #[godot_api]
 impl SomeObject {
     #[func]
@@ -42,3 +46,9 @@ pub fn initialize_init_level_test(level: InitLevel) {
 fn class_run_during_servers_init() {
     assert!(HAS_RUN.load(Ordering::Acquire));
 }
+
+#[itest]
+fn class_init_level_override() {
+    assert_eq!(SomeObject::INIT_LEVEL, InitLevel::Core);
+    assert_eq!(ServersObject::INIT_LEVEL, InitLevel::Servers);
+}
@@ -0,0 +1,52 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::obj::{Gd, LeakTracked, LeakTracker, NewGd};
+use godot::register::GodotClass;
+
+use crate::framework::{expect_panic, itest};
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct TrackedObj {
+    other: Option<Gd<TrackedObj>>,
+    _tracked: LeakTracked<Self>,
+}
+
+#[itest]
+fn leak_tracker_counts_instances() {
+    assert_eq!(LeakTracker::<TrackedObj>::live_count(), 0);
+
+    let first = TrackedObj::new_gd();
+    let second = TrackedObj::new_gd();
+    assert_eq!(LeakTracker::<TrackedObj>::live_count(), 2);
+
+    drop(first);
+    assert_eq!(LeakTracker::<TrackedObj>::live_count(), 1);
+
+    drop(second);
+    LeakTracker::<TrackedObj>::assert_all_freed();
+}
+
+#[itest]
+fn leak_tracker_detects_cycle() {
+    let mut first = TrackedObj::new_gd();
+    let second = TrackedObj::new_gd();
+    first.bind_mut().other = Some(second.clone());
+    second.clone().bind_mut().other = Some(first.clone());
+
+    drop(second);
+    expect_panic("reference cycle keeps instances alive", || {
+        LeakTracker::<TrackedObj>::assert_all_freed();
+    });
+    assert_eq!(LeakTracker::<TrackedObj>::live_count(), 2);
+
+    // Break the cycle.
+    first.bind_mut().other = None;
+    drop(first);
+    LeakTracker::<TrackedObj>::assert_all_freed();
+}
@@ -15,6 +15,7 @@ mod enum_test;
 #[cfg(since_api = "4.3")]
 mod get_property_list_test;
 mod init_level_test;
+mod leak_tracker_test;
 mod object_arg_test;
 mod object_swap_test;
 mod object_test;
@@ -134,6 +134,28 @@ fn object_engine_display() {
     obj.free();
 }
 
+#[itest]
+fn object_engine_display_freed() {
+    let obj = Node3D::new_alloc();
+    let id = obj.instance_id();
+    obj.clone().free();
+
+    let actual = format!(".:{obj}:.");
+    let expected = format!(".:<Freed Object#{id}>:.");
+
+    assert_eq!(actual, expected);
+}
+
+#[itest]
+fn object_instance_id_hex() {
+    let obj = Node3D::new_alloc();
+    let id = obj.instance_id();
+
+    assert_eq!(format!("{id:x}"), format!("{:x}", id.to_u64()));
+    assert_eq!(format!("{id:#x}"), format!("{:#x}", id.to_u64()));
+    obj.free();
+}
+
 #[itest]
 fn object_debug() {
     let obj = Node3D::new_alloc();
@@ -254,6 +276,30 @@ fn object_dynamic_free() {
         .expect_err("dynamic free() call must destroy object");
 }
 
+#[itest]
+fn object_user_with_and_with_mut() {
+    let mut obj = Gd::from_object(RefcPayload { value: 10 });
+
+    let old = obj.with_mut(|payload| {
+        let old = payload.value;
+        payload.value *= 2;
+        old
+    });
+    assert_eq!(old, 10);
+    assert_eq!(obj.with(|payload| payload.value), 20);
+
+    // The borrow ends with the closure, so the object can be bound again right away.
+    obj.with_mut(|payload| payload.value += 1);
+    assert_eq!(obj.bind().value, 21);
+
+    let guard = obj.bind();
+    let mut copy = obj.clone();
+    expect_panic("with_mut() while bound", move || {
+        copy.with_mut(|payload| payload.value = 0);
+    });
+    drop(guard);
+}
+
 #[itest]
 fn object_user_bind_after_free() {
     let obj = Gd::from_object(ObjPayload {});
@@ -389,6 +435,29 @@ fn object_engine_use_after_free_varcall() {
     });
 }
 
+#[itest]
+fn object_set_property_deferred() {
+    let mut node = Node3D::new_alloc();
+    node.set_property_deferred("visible", false);
+
+    // Only applied at the end of the frame (checked in `test_set_property_deferred` in `SpecialTests.gd`).
+    assert!(node.is_visible());
+    node.queue_free();
+}
+
+// Used in `SpecialTests.gd`, which can let frames pass between calls.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+pub struct ObjectFrameTest;
+
+#[godot_api]
+impl ObjectFrameTest {
+    #[func]
+    fn set_property_deferred(mut object: Gd<Object>, property: StringName, value: Variant) {
+        object.set_property_deferred(&property, value);
+    }
+}
+
 #[itest]
 fn object_user_eq() {
     let value: i16 = 17943;
@@ -679,6 +748,23 @@ fn object_engine_bad_downcast() {
     object2.free();
 }
 
+#[itest]
+fn object_is_class_exact() {
+    let node3d = Node3D::new_alloc();
+    assert!(node3d.is_class_exact::<Node3D>());
+    assert!(!node3d.is_class_exact::<Node>());
+    assert!(!node3d.is_class_exact::<Camera3D>());
+
+    let node = node3d.upcast::<Node>();
+    assert!(node.is_class_exact::<Node3D>());
+    node.free();
+
+    let user = ObjPayload::new_alloc();
+    assert!(user.is_class_exact::<ObjPayload>());
+    assert!(!user.is_class_exact::<Object>());
+    user.free();
+}
+
 #[itest]
 fn object_engine_accept_polymorphic() {
     let mut node = Camera3D::new_alloc();
@@ -906,6 +992,11 @@ impl ObjPayload {
     pub fn get_panic_line() -> u32 {
         line!() - 5
     }
+
+    #[func(no_panic_catch)]
+    fn take_1_int_no_catch(&self, value: i64) -> i64 {
+        value
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
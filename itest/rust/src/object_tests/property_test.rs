@@ -5,8 +5,8 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::{dict, Color, Dictionary, GString, Variant, VariantType};
-use godot::classes::{INode, IRefCounted, Node, Object, RefCounted, Resource, Texture};
+use godot::builtin::{dict, Color, Dictionary, GString, StringName, Variant, VariantType};
+use godot::classes::{ClassDb, INode, IRefCounted, Node, Object, RefCounted, Resource, Texture};
 use godot::global::{PropertyHint, PropertyUsageFlags};
 use godot::meta::{GodotConvert, PropertyHintInfo, ToGodot};
 use godot::obj::{Base, EngineBitfield, EngineEnum, Gd, NewAlloc, NewGd};
@@ -366,6 +366,28 @@ fn derive_export() {
     check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
 }
 
+#[derive(GodotClass)]
+#[class(no_init)]
+pub struct ExportFlags {
+    // @export_flags("Walk", "Swim:4", "Fly:8")
+    #[export(flags = (Walk, Swim = 4, Fly = 8))]
+    movement: u32,
+}
+
+#[itest]
+fn export_flags_hint() {
+    let property = ClassDb::singleton()
+        .class_properties("ExportFlags")
+        .into_iter()
+        .find(|property| property.name == StringName::from("movement"))
+        .expect("exported property is registered");
+
+    assert_eq!(property.variant_type, VariantType::INT);
+    assert_eq!(property.hint, PropertyHint::FLAGS);
+    assert_eq!(property.hint_string, GString::from("Walk,Swim:4,Fly:8"));
+    assert_eq!(property.usage, PropertyUsageFlags::DEFAULT);
+}
+
 #[derive(GodotClass)]
 #[class(init, base=Resource)]
 pub struct CustomResource {}
@@ -447,6 +469,136 @@ fn override_export() {
     check_property(&property, "usage", PropertyUsageFlags::GROUP.ord());
 }
 
+#[derive(GodotClass)]
+#[class(init)]
+struct ExportColor {
+    #[export]
+    with_alpha: Color,
+
+    #[export(color_no_alpha)]
+    no_alpha: Color,
+
+    #[export(alpha_in_inspector = false)]
+    no_alpha_synonym: Color,
+
+    #[export(alpha_in_inspector = true)]
+    with_alpha_explicit: Color,
+}
+
+#[itest]
+fn export_color_no_alpha() {
+    let class = ExportColor::new_gd();
+    let find = |name: &str| {
+        class
+            .get_property_list()
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap()
+    };
+
+    for name in ["no_alpha", "no_alpha_synonym"] {
+        let property = find(name);
+        check_property(&property, "type", VariantType::COLOR.ord());
+        check_property(&property, "hint", PropertyHint::COLOR_NO_ALPHA.ord());
+        check_property(&property, "hint_string", "");
+    }
+
+    for name in ["with_alpha", "with_alpha_explicit"] {
+        check_property(&find(name), "hint", PropertyHint::NONE.ord());
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct SaveControl {
+    #[export(no_save)]
+    editor_only: i64,
+
+    #[export(range = (0.0, 10.0), no_save)]
+    editor_only_range: f64,
+
+    #[var(save)]
+    storage_only: i64,
+}
+
+#[itest]
+fn export_no_save_var_save() {
+    let class = SaveControl::new_gd();
+    let find = |name: &str| {
+        class
+            .get_property_list()
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap()
+    };
+
+    check_property(
+        &find("editor_only"),
+        "usage",
+        PropertyUsageFlags::EDITOR.ord(),
+    );
+
+    let property = find("editor_only_range");
+    check_property(&property, "usage", PropertyUsageFlags::EDITOR.ord());
+    check_property(&property, "hint", PropertyHint::RANGE.ord());
+
+    check_property(
+        &find("storage_only"),
+        "usage",
+        PropertyUsageFlags::STORAGE.ord(),
+    );
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct SaveSnapshot {
+    #[export]
+    health: i64,
+
+    #[export]
+    resource: Option<Gd<Resource>>,
+
+    #[export(no_save)]
+    editor_only: i64,
+
+    #[var(save)]
+    untyped: Variant,
+}
+
+#[itest]
+fn object_variant_dict_roundtrip() {
+    let resource = Resource::new_gd();
+
+    let mut original = SaveSnapshot::new_gd();
+    {
+        let mut original = original.bind_mut();
+        original.health = 42;
+        original.resource = Some(resource.clone());
+        original.editor_only = 7;
+        original.untyped = resource.to_variant();
+    }
+
+    let dict = original.to_variant_dict();
+    assert_eq!(dict.get("health"), Some(42.to_variant()));
+    assert_eq!(
+        dict.get("resource"),
+        Some(resource.instance_id().to_variant())
+    );
+    assert_eq!(dict.get("editor_only"), None);
+
+    // Untyped properties keep the object itself.
+    assert_eq!(dict.get("untyped"), Some(resource.to_variant()));
+
+    let mut restored = SaveSnapshot::new_gd();
+    restored.from_variant_dict(&dict);
+
+    let restored = restored.bind();
+    assert_eq!(restored.health, 42);
+    assert_eq!(restored.resource, Some(resource.clone()));
+    assert_eq!(restored.editor_only, 0);
+    assert_eq!(restored.untyped, resource.to_variant());
+}
+
 fn check_property(property: &Dictionary, key: &str, expected: impl ToGodot) {
     assert_eq!(property.get_or_nil(key), expected.to_variant());
 }
@@ -19,9 +19,9 @@ use godot::classes::resource_loader::CacheMode;
 #[cfg(feature = "codegen-full")]
 use godot::classes::Material;
 use godot::classes::{
-    BoxMesh, IEditorPlugin, INode, INode2D, IPrimitiveMesh, IRefCounted, IResourceFormatLoader,
-    IRigidBody2D, InputEvent, InputEventAction, Node, Node2D, Object, PrimitiveMesh, RefCounted,
-    ResourceFormatLoader, ResourceLoader, Viewport, Window,
+    BoxMesh, EditorPlugin, IEditorPlugin, INode, INode2D, IPrimitiveMesh, IRefCounted,
+    IResourceFormatLoader, IRigidBody2D, InputEvent, InputEventAction, Node, Node2D, Object,
+    PrimitiveMesh, RefCounted, ResourceFormatLoader, ResourceLoader, Viewport, Window,
 };
 use godot::meta::ToGodot;
 use godot::obj::{Base, Gd, NewAlloc, NewGd};
@@ -35,6 +35,15 @@ struct WithoutInit {
     some_base: Base<RefCounted>,
 }
 
+/// Class that can only be instantiated through subclasses, e.g. from GDScript.
+#[derive(GodotClass, Debug)]
+#[class(init, abstract, base=RefCounted)]
+struct AbstractBase {
+    #[var]
+    #[init(val = 7)]
+    base_value: i32,
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[derive(GodotClass, Debug)]
@@ -598,6 +607,68 @@ fn test_notifications() {
     obj.free();
 }
 
+#[itest]
+fn test_notifications_propagated() {
+    let parent = NotificationTest::new_alloc();
+    let child = NotificationTest::new_alloc();
+    parent.clone().upcast::<Node>().add_child(&child);
+
+    let mut node = parent.clone().upcast::<Node>();
+    node.propagate_notify(NodeNotification::EDITOR_POST_SAVE);
+
+    for obj in [&parent, &child] {
+        assert_eq!(
+            obj.bind().sequence.last(),
+            Some(&ReceivedEvent::Notification(
+                NodeNotification::EDITOR_POST_SAVE
+            ))
+        );
+    }
+    parent.free();
+}
+
+#[itest]
+fn test_notifications_group(ctx: &TestContext) {
+    let member = NotificationTest::new_alloc();
+    let outsider = NotificationTest::new_alloc();
+
+    let mut root = ctx.scene_tree.clone();
+    root.add_child(&member);
+    root.add_child(&outsider);
+    member.clone().upcast::<Node>().add_to_group("notified");
+
+    root.get_tree()
+        .unwrap()
+        .call_group_notification("notified", NodeNotification::EDITOR_POST_SAVE);
+
+    let expected = ReceivedEvent::Notification(NodeNotification::EDITOR_POST_SAVE);
+    assert_eq!(member.bind().sequence.last(), Some(&expected));
+    assert_ne!(outsider.bind().sequence.last(), Some(&expected));
+
+    member.free();
+    outsider.free();
+}
+
+#[itest]
+fn test_notification_godot_name() {
+    assert_eq!(
+        NodeNotification::READY.godot_name(),
+        Some("NOTIFICATION_READY")
+    );
+    assert_eq!(
+        NodeNotification::from(10).godot_name(),
+        Some("NOTIFICATION_ENTER_TREE")
+    );
+
+    // Notifications inherited from Object.
+    assert_eq!(
+        NodeNotification::PREDELETE.godot_name(),
+        Some("NOTIFICATION_PREDELETE")
+    );
+
+    assert_eq!(NodeNotification::Unknown(123456).godot_name(), None);
+}
+
 #[itest]
 fn test_get_called() {
     let obj = GetTest::new_gd();
@@ -782,6 +853,16 @@ impl GetSetTest {
 #[class(no_init, base = EditorPlugin, tool)]
 struct CustomEditorPlugin;
 
+// Shorthand for `base = EditorPlugin, tool`.
+#[derive(GodotClass)]
+#[class(no_init, editor_plugin)]
+struct ShorthandEditorPlugin {
+    base: Base<EditorPlugin>,
+}
+
+#[godot_api]
+impl IEditorPlugin for ShorthandEditorPlugin {}
+
 // Just override EditorPlugin::edit() to verify method is declared with Option<T>.
 // See https://github.com/godot-rust/gdext/issues/494.
 #[godot_api]
@@ -31,6 +31,9 @@ impl HasConstants {
     #[constant]
     const D: usize = 20 + 33 * 45;
 
+    #[constant]
+    const E: i32 = -100;
+
     #[constant]
     #[rustfmt::skip]
     const DONT_PANIC_WITH_SEGMENTED_PATH_ATTRIBUTE: bool = true;
@@ -72,11 +75,12 @@ fn class_has_integer_constant<T: GodotClass>(name: &str) -> bool {
 
 #[itest]
 fn constants_correct_value() {
-    const CONSTANTS: [(&str, i64); 5] = [
+    const CONSTANTS: [(&str, i64); 6] = [
         ("A", HasConstants::A),
         ("B", HasConstants::B as i64),
         ("C", HasConstants::C as i64),
         ("D", HasConstants::D as i64),
+        ("E", HasConstants::E as i64),
         (
             "CFG_REMOVES_DUPLICATE_CONSTANT_DEF",
             HasConstants::CFG_REMOVES_DUPLICATE_CONSTANT_DEF,
@@ -0,0 +1,75 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{IResource, Resource};
+use godot::obj::{Base, Gd, NewGd};
+use godot::register::{godot_api, Resource};
+
+use crate::framework::itest;
+
+#[derive(Resource)]
+struct MyGameSettings {
+    #[export]
+    volume: f32,
+}
+
+#[derive(Resource)]
+#[class(rename = HudConfig)]
+struct RenamedSettings {
+    #[export]
+    #[init(val = 3)]
+    lives: i32,
+}
+
+#[derive(Resource)]
+#[class(user_init)]
+struct CustomInitSettings {
+    difficulty: i32,
+    base: Base<Resource>,
+}
+
+#[godot_api]
+impl IResource for CustomInitSettings {
+    fn init(base: Base<Resource>) -> Self {
+        Self {
+            difficulty: 2,
+            base,
+        }
+    }
+}
+
+#[itest]
+fn derive_resource_defaults() {
+    let settings = MyGameSettings::new_gd();
+    assert_eq!(settings.bind().volume, 0.0);
+
+    let resource: Gd<Resource> = settings.upcast();
+    assert_eq!(resource.get_class(), "MyGameSettings".into());
+
+    assert_eq!(
+        MyGameSettings::default_resource_path(),
+        "res://my_game_settings.tres".into()
+    );
+}
+
+#[itest]
+fn derive_resource_explicit_class_attr() {
+    let settings = RenamedSettings::new_gd();
+    assert_eq!(settings.bind().lives, 3);
+    assert_eq!(settings.get_class(), "HudConfig".into());
+
+    assert_eq!(
+        RenamedSettings::default_resource_path(),
+        "res://hud_config.tres".into()
+    );
+}
+
+#[itest]
+fn derive_resource_user_init() {
+    let settings = CustomInitSettings::new_gd();
+    assert_eq!(settings.bind().difficulty, 2);
+}
@@ -247,6 +247,28 @@ impl IRefCounted for GdSelfObj {
     }
 }
 
+/// Stores `Gd` pointers received through `#[func(gd_self)]`, like an observer registry.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct GdSelfHub {
+    subscribers: Vec<Gd<GdSelfSubscriber>>,
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct GdSelfSubscriber {
+    notified: i32,
+}
+
+#[godot_api]
+impl GdSelfSubscriber {
+    #[func(gd_self)]
+    fn subscribe(this: Gd<Self>, mut hub: Gd<GdSelfHub>) {
+        // `this` is an owned pointer, so it can be cloned and stored beyond the call.
+        hub.bind_mut().subscribers.push(this.clone());
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Tests
 
@@ -264,6 +286,29 @@ fn cfg_doesnt_interfere_with_valid_method_impls() {
     );
 }
 
+#[itest]
+fn func_gd_self_can_be_stored() {
+    let subscriber = GdSelfSubscriber::new_gd();
+    let hub = GdSelfHub::new_gd();
+
+    // Dynamic call, going through the generated FFI shim.
+    subscriber
+        .clone()
+        .upcast::<RefCounted>()
+        .call("subscribe", &[hub.to_variant()]);
+
+    let stored = hub.bind().subscribers[0].clone();
+    assert_eq!(stored, subscriber);
+
+    // The stored pointer keeps the object alive on its own.
+    let id = subscriber.instance_id();
+    drop(subscriber);
+    assert!(id.lookup_validity());
+
+    stored.clone().bind_mut().notified += 1;
+    assert_eq!(hub.bind().subscribers[0].bind().notified, 1);
+}
+
 #[itest]
 fn cfg_removes_or_keeps_methods() {
     assert!(class_has_method::<GdSelfObj>(
@@ -8,6 +8,7 @@
 mod constant_test;
 mod conversion_test;
 mod derive_godotconvert_test;
+mod derive_resource_test;
 mod func_test;
 mod gdscript_ffi_test;
 mod multiple_impl_blocks_test;
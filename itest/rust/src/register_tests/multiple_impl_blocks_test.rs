@@ -6,6 +6,7 @@
  */
 
 use godot::classes::IObject;
+use godot::meta::ToGodot;
 use godot::obj::{Base, Gd, NewAlloc};
 use godot::register::{godot_api, GodotClass};
 
@@ -75,3 +76,76 @@ fn godot_api_multiple_impl_blocks() {
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
+
+mod traits {
+    pub trait Describe {
+        fn describe(&self) -> String;
+        fn describe_static(prefix: String) -> String;
+        fn rust_only(&self) -> i32;
+    }
+
+    pub trait Scale<T> {
+        fn scaled(&self, factor: i32) -> i32;
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct UserTraitImpl {
+    value: i32,
+}
+
+#[godot_api]
+impl UserTraitImpl {
+    #[func]
+    fn set_value(&mut self, value: i32) {
+        self.value = value;
+    }
+}
+
+#[godot_api(secondary, trait = traits::Describe)]
+impl traits::Describe for UserTraitImpl {
+    #[func]
+    fn describe(&self) -> String {
+        format!("value={}", self.value)
+    }
+
+    #[func]
+    fn describe_static(prefix: String) -> String {
+        format!("{prefix}: static")
+    }
+
+    fn rust_only(&self) -> i32 {
+        self.value
+    }
+}
+
+// Generic user traits: the trait path is compared without generic arguments.
+#[godot_api(secondary, trait = traits::Scale<i64>)]
+impl traits::Scale<i64> for UserTraitImpl {
+    #[func]
+    fn scaled(&self, factor: i32) -> i32 {
+        self.value * factor
+    }
+}
+
+/// Test that #[func] methods of user-defined trait impls are registered, while other trait methods are not.
+#[itest]
+fn godot_api_user_trait_impl() {
+    let mut obj = UserTraitImpl::new_alloc();
+    obj.call("set_value", &[7.to_variant()]);
+
+    assert!(obj.has_method("describe"));
+    assert_eq!(obj.call("describe", &[]), "value=7".to_variant());
+    assert_eq!(
+        obj.call("describe_static", &["prefix".to_variant()]),
+        "prefix: static".to_variant()
+    );
+
+    assert_eq!(obj.call("scaled", &[3.to_variant()]), 21.to_variant());
+
+    assert!(!obj.has_method("rust_only"));
+    assert_eq!(traits::Describe::rust_only(&*obj.bind()), 7);
+
+    obj.free();
+}